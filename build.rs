@@ -0,0 +1,4 @@
+fn main() {
+    // Re-embed `sqlx::migrate!` when migration files change.
+    println!("cargo:rerun-if-changed=migrations");
+}
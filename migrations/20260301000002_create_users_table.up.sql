@@ -0,0 +1,17 @@
+CREATE TABLE users (
+    id SERIAL PRIMARY KEY,
+    role_id INTEGER REFERENCES user_roles (id),
+    username VARCHAR(50) NOT NULL,
+    password TEXT NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    email VARCHAR(255) NOT NULL,
+    status BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    CONSTRAINT users_username_key UNIQUE (username),
+    CONSTRAINT users_email_key UNIQUE (email)
+);
+
+CREATE INDEX users_role_id_idx ON users (role_id);
+CREATE INDEX users_deleted_at_idx ON users (deleted_at);
@@ -0,0 +1,318 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, RwLock},
+};
+
+use crate::application::{error::ApplicationError, error_code::ErrorCode};
+
+#[derive(Debug, Clone)]
+pub enum Policy {
+    /// Any authenticated user.
+    Authenticated,
+    /// The user's role must be one of the listed role names.
+    AnyRole(Vec<&'static str>),
+    /// The user's role must grant every listed permission, e.g. `users:write`.
+    AllPermissions(Vec<&'static str>),
+}
+
+/// What the authorization layer knows about the caller.
+#[derive(Debug, Clone, Copy)]
+pub struct Subject<'a> {
+    pub role: Option<&'a str>,
+    pub permissions: &'a HashSet<String>,
+}
+
+impl Policy {
+    fn allows(&self, subject: &Subject<'_>) -> bool {
+        match self {
+            Policy::Authenticated => true,
+            Policy::AnyRole(roles) => subject.role.is_some_and(|r| roles.contains(&r)),
+            Policy::AllPermissions(permissions) => {
+                permissions.iter().all(|p| subject.permissions.contains(*p))
+            }
+        }
+    }
+}
+
+/// Named access-control policies, keyed by names like `users:create`.
+///
+/// Routes refer to policies by name so the rules can be declared in one
+/// place and checked without going through HTTP.
+#[derive(Debug, Clone, Default)]
+pub struct PolicyRegistry {
+    policies: HashMap<&'static str, Policy>,
+}
+
+impl PolicyRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, name: &'static str, policy: Policy) -> &mut Self {
+        self.policies.insert(name, policy);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Policy> {
+        self.policies.get(name)
+    }
+
+    pub fn authorize(&self, name: &str, subject: &Subject<'_>) -> Result<(), ApplicationError> {
+        // Fail closed: a route pointing at a policy nobody registered is a bug.
+        let policy = self
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("Policy `{name}` is not registered"))?;
+
+        if !policy.allows(subject) {
+            return Err(ApplicationError::Forbidden(
+                ErrorCode::PERMISSION_DENIED,
+                "You do not have permission to perform this action".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Role id → permission names, shared through `AppState` so the permission
+/// set is not reloaded on every request. Invalidate a role whenever its
+/// permission assignments change.
+///
+/// Take a [`generation`](Self::generation) before loading a set and hand it
+/// to [`insert`](Self::insert): a load that raced with an invalidation is
+/// returned to the caller but not cached, so a stale set never outlives the
+/// change that made it stale.
+#[derive(Debug, Clone, Default)]
+pub struct PermissionCache {
+    state: Arc<RwLock<PermissionCacheState>>,
+}
+
+#[derive(Debug, Default)]
+struct PermissionCacheState {
+    roles: HashMap<i32, Arc<HashSet<String>>>,
+    /// Bumped per role by `invalidate`.
+    generations: HashMap<i32, u64>,
+    /// Bumped by `clear`, invalidating every role at once.
+    epoch: u64,
+}
+
+impl PermissionCacheState {
+    fn generation(&self, role_id: i32) -> CacheGeneration {
+        CacheGeneration {
+            epoch: self.epoch,
+            role: self.generations.get(&role_id).copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// Snapshot of a role's cache state, see [`PermissionCache::generation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheGeneration {
+    epoch: u64,
+    role: u64,
+}
+
+impl PermissionCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, role_id: i32) -> Option<Arc<HashSet<String>>> {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .roles
+            .get(&role_id)
+            .cloned()
+    }
+
+    pub fn generation(&self, role_id: i32) -> CacheGeneration {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .generation(role_id)
+    }
+
+    /// Caches `permissions` unless `role_id` was invalidated since
+    /// `generation` was taken.
+    pub fn insert(
+        &self,
+        role_id: i32,
+        generation: CacheGeneration,
+        permissions: HashSet<String>,
+    ) -> Arc<HashSet<String>> {
+        let permissions = Arc::new(permissions);
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+
+        if state.generation(role_id) == generation {
+            state.roles.insert(role_id, permissions.clone());
+        }
+
+        permissions
+    }
+
+    pub fn invalidate(&self, role_id: i32) {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+
+        state.roles.remove(&role_id);
+        *state.generations.entry(role_id).or_default() += 1;
+    }
+
+    pub fn clear(&self) {
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+
+        state.roles.clear();
+        state.generations.clear();
+        state.epoch += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> PolicyRegistry {
+        let mut registry = PolicyRegistry::new();
+        registry
+            .register("users:read", Policy::Authenticated)
+            .register("users:create", Policy::AnyRole(vec!["admin"]))
+            .register("users:delete", Policy::AllPermissions(vec!["users:write"]));
+        registry
+    }
+
+    fn permissions(names: &[&str]) -> HashSet<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn authenticated_policy_allows_any_role() {
+        let registry = registry();
+        let none = HashSet::new();
+
+        let anonymous_role = Subject {
+            role: None,
+            permissions: &none,
+        };
+        let staff = Subject {
+            role: Some("staff"),
+            permissions: &none,
+        };
+
+        assert!(registry.authorize("users:read", &anonymous_role).is_ok());
+        assert!(registry.authorize("users:read", &staff).is_ok());
+    }
+
+    #[test]
+    fn role_policy_allows_listed_role() {
+        let none = HashSet::new();
+        let admin = Subject {
+            role: Some("admin"),
+            permissions: &none,
+        };
+
+        assert!(registry().authorize("users:create", &admin).is_ok());
+    }
+
+    #[test]
+    fn role_policy_forbids_other_roles() {
+        let registry = registry();
+        let none = HashSet::new();
+
+        let staff = Subject {
+            role: Some("staff"),
+            permissions: &none,
+        };
+        let no_role = Subject {
+            role: None,
+            permissions: &none,
+        };
+
+        assert!(matches!(
+            registry.authorize("users:create", &staff),
+            Err(ApplicationError::Forbidden(..))
+        ));
+        assert!(matches!(
+            registry.authorize("users:create", &no_role),
+            Err(ApplicationError::Forbidden(..))
+        ));
+    }
+
+    #[test]
+    fn permission_policy_checks_granted_permissions() {
+        let registry = registry();
+        let writer = permissions(&["users:read", "users:write"]);
+        let reader = permissions(&["users:read"]);
+
+        let writer = Subject {
+            role: Some("staff"),
+            permissions: &writer,
+        };
+        let reader = Subject {
+            role: Some("admin"),
+            permissions: &reader,
+        };
+
+        assert!(registry.authorize("users:delete", &writer).is_ok());
+        assert!(matches!(
+            registry.authorize("users:delete", &reader),
+            Err(ApplicationError::Forbidden(..))
+        ));
+    }
+
+    #[test]
+    fn unknown_policy_is_denied() {
+        let none = HashSet::new();
+        let admin = Subject {
+            role: Some("admin"),
+            permissions: &none,
+        };
+
+        assert!(matches!(
+            registry().authorize("users:purge", &admin),
+            Err(ApplicationError::Unexpected(_))
+        ));
+    }
+
+    #[test]
+    fn permission_cache_invalidates_single_role() {
+        let cache = PermissionCache::new();
+        cache.insert(1, cache.generation(1), permissions(&["users:write"]));
+        cache.insert(2, cache.generation(2), permissions(&["users:read"]));
+
+        cache.invalidate(1);
+
+        assert!(cache.get(1).is_none());
+        assert!(cache.get(2).is_some_and(|p| p.contains("users:read")));
+    }
+
+    #[test]
+    fn permission_cache_drops_loads_that_raced_an_invalidation() {
+        let cache = PermissionCache::new();
+
+        // A request misses and starts loading role 1 ...
+        let generation = cache.generation(1);
+        // ... while its permissions are replaced and the role invalidated ...
+        cache.invalidate(1);
+        // ... then the request stores what it read before the change.
+        let stale = cache.insert(1, generation, permissions(&["users:write"]));
+
+        assert!(stale.contains("users:write"));
+        assert!(cache.get(1).is_none());
+
+        let generation = cache.generation(1);
+        cache.insert(1, generation, permissions(&["users:read"]));
+
+        assert!(cache.get(1).is_some_and(|p| p.contains("users:read")));
+    }
+
+    #[test]
+    fn permission_cache_drops_loads_that_raced_a_clear() {
+        let cache = PermissionCache::new();
+
+        let generation = cache.generation(1);
+        cache.clear();
+        cache.insert(1, generation, permissions(&["users:write"]));
+
+        assert!(cache.get(1).is_none());
+    }
+}
@@ -1,19 +1,118 @@
 use thiserror::Error;
 
+use crate::{
+    application::error_code::ErrorCode,
+    infrastructure::sql::error::{ConstraintKind, ConstraintViolation, DbError},
+};
+
+/// Every variant carries an `ErrorCode` from the catalogue, except
+/// `Unexpected`, which is always reported as `INTERNAL_ERROR`.
 #[derive(Debug, Error)]
 pub enum ApplicationError {
     #[error("Not found")]
-    NotFound(String),
+    NotFound(ErrorCode, String),
 
     #[error("Conflict")]
-    Conflict(String),
+    Conflict(ErrorCode, String),
+
+    /// A single input field clashes with existing data, e.g. a taken username.
+    #[error("Field conflict")]
+    FieldConflict {
+        code: ErrorCode,
+        field: String,
+        message: String,
+    },
+
+    /// A single input field holds a value the data store rejected.
+    #[error("Invalid field")]
+    InvalidField {
+        code: ErrorCode,
+        field: String,
+        message: String,
+    },
 
     #[error("Unauthorized")]
-    Unauthorized(String),
+    Unauthorized(ErrorCode, String),
 
     #[error("Forbidden")]
-    Forbidden(String),
+    Forbidden(ErrorCode, String),
+
+    #[error("Unprocessable entity")]
+    UnprocessableEntity(ErrorCode, String),
 
     #[error("Unexpected error")]
-    Unexpected(#[from] anyhow::Error),
+    Unexpected(anyhow::Error),
+}
+
+impl ApplicationError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ApplicationError::NotFound(code, _)
+            | ApplicationError::Conflict(code, _)
+            | ApplicationError::Unauthorized(code, _)
+            | ApplicationError::Forbidden(code, _)
+            | ApplicationError::UnprocessableEntity(code, _) => *code,
+            ApplicationError::FieldConflict { code, .. }
+            | ApplicationError::InvalidField { code, .. } => *code,
+            ApplicationError::Unexpected(_) => ErrorCode::INTERNAL_ERROR,
+        }
+    }
+
+    /// Replaces the generic code of a unique-constraint conflict with the
+    /// module's own, looked up by field, e.g. `email` -> `USER_EMAIL_TAKEN`.
+    pub fn with_conflict_codes(self, codes: &[(&str, ErrorCode)]) -> Self {
+        match self {
+            ApplicationError::FieldConflict {
+                code,
+                field,
+                message,
+            } => ApplicationError::FieldConflict {
+                code: codes
+                    .iter()
+                    .find(|(name, _)| *name == field)
+                    .map_or(code, |(_, code)| *code),
+                field,
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl From<anyhow::Error> for ApplicationError {
+    fn from(err: anyhow::Error) -> Self {
+        match err.downcast_ref::<DbError>() {
+            Some(DbError::Constraint(violation)) => violation.clone().into(),
+            _ => ApplicationError::Unexpected(err),
+        }
+    }
+}
+
+impl From<ConstraintViolation> for ApplicationError {
+    fn from(violation: ConstraintViolation) -> Self {
+        let ConstraintViolation { kind, field, .. } = violation;
+
+        match kind {
+            ConstraintKind::Unique => ApplicationError::FieldConflict {
+                code: ErrorCode::FIELD_CONFLICT,
+                message: format!("{field} already exists"),
+                field,
+            },
+            ConstraintKind::ForeignKey => ApplicationError::InvalidField {
+                code: ErrorCode::FIELD_INVALID_REFERENCE,
+                message: format!("{field} references a record that does not exist"),
+                field,
+            },
+            ConstraintKind::Check => ApplicationError::InvalidField {
+                code: ErrorCode::FIELD_INVALID,
+                message: format!("{field} is invalid"),
+                field,
+            },
+            ConstraintKind::NotNull => ApplicationError::InvalidField {
+                code: ErrorCode::FIELD_REQUIRED,
+                message: format!("{field} is required"),
+                field,
+            },
+        }
+    }
 }
@@ -0,0 +1,105 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// Stable, machine-readable identifier of an error, returned to clients as
+/// `code` next to the human-readable message. Codes are part of the API
+/// contract: add new ones freely, but never rename or reuse an existing one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct ErrorCode(&'static str);
+
+impl ErrorCode {
+    pub const fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+macro_rules! error_codes {
+    ($($(#[$meta:meta])* $name:ident = $code:literal,)*) => {
+        impl ErrorCode {
+            $($(#[$meta])* pub const $name: ErrorCode = ErrorCode($code);)*
+
+            /// Every code in the catalogue.
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$name),*];
+        }
+    };
+}
+
+error_codes! {
+    // ===== GENERIC =====
+    INTERNAL_ERROR = "INTERNAL_ERROR",
+    VALIDATION_FAILED = "VALIDATION_FAILED",
+    /// A unique constraint rejected the write.
+    FIELD_CONFLICT = "FIELD_CONFLICT",
+    /// A foreign key points at a record that does not exist.
+    FIELD_INVALID_REFERENCE = "FIELD_INVALID_REFERENCE",
+    /// A check constraint rejected the value.
+    FIELD_INVALID = "FIELD_INVALID",
+    FIELD_REQUIRED = "FIELD_REQUIRED",
+    PERMISSION_DENIED = "PERMISSION_DENIED",
+
+    // ===== REQUEST =====
+    INVALID_REQUEST = "INVALID_REQUEST",
+    INVALID_JSON_BODY = "INVALID_JSON_BODY",
+    MALFORMED_JSON_BODY = "MALFORMED_JSON_BODY",
+    UNSUPPORTED_CONTENT_TYPE = "UNSUPPORTED_CONTENT_TYPE",
+    INVALID_QUERY = "INVALID_QUERY",
+    INVALID_PATH_PARAMETER = "INVALID_PATH_PARAMETER",
+
+    // ===== AUTH =====
+    AUTH_REQUIRED = "AUTH_REQUIRED",
+    AUTH_MISSING_TOKEN = "AUTH_MISSING_TOKEN",
+    AUTH_MALFORMED_HEADER = "AUTH_MALFORMED_HEADER",
+    AUTH_INVALID_TOKEN = "AUTH_INVALID_TOKEN",
+    AUTH_USER_NOT_FOUND = "AUTH_USER_NOT_FOUND",
+    AUTH_INVALID_CREDENTIALS = "AUTH_INVALID_CREDENTIALS",
+    AUTH_USER_INACTIVE = "AUTH_USER_INACTIVE",
+    AUTH_INVALID_REFRESH_TOKEN = "AUTH_INVALID_REFRESH_TOKEN",
+    AUTH_REFRESH_TOKEN_REUSED = "AUTH_REFRESH_TOKEN_REUSED",
+    AUTH_INCORRECT_PASSWORD = "AUTH_INCORRECT_PASSWORD",
+
+    // ===== USER =====
+    USER_NOT_FOUND = "USER_NOT_FOUND",
+    USER_EMAIL_TAKEN = "USER_EMAIL_TAKEN",
+    USER_USERNAME_TAKEN = "USER_USERNAME_TAKEN",
+
+    // ===== USER ROLE =====
+    USER_ROLE_NOT_FOUND = "USER_ROLE_NOT_FOUND",
+    USER_ROLE_NAME_TAKEN = "USER_ROLE_NAME_TAKEN",
+    USER_ROLE_UNKNOWN_PERMISSION = "USER_ROLE_UNKNOWN_PERMISSION",
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn codes_are_unique() {
+        let mut seen = HashSet::new();
+
+        for code in ErrorCode::ALL {
+            assert!(seen.insert(code.as_str()), "duplicate error code `{code}`");
+        }
+    }
+
+    #[test]
+    fn codes_are_screaming_snake_case() {
+        for code in ErrorCode::ALL {
+            assert!(
+                code.as_str()
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'),
+                "`{code}` is not SCREAMING_SNAKE_CASE"
+            );
+        }
+    }
+}
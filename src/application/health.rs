@@ -0,0 +1,214 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use tracing::Instrument;
+
+pub const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+pub type CheckFuture<'a> = Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send + 'a>>;
+
+/// How much the service depends on what a check covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Criticality {
+    /// The service is unavailable while the check fails.
+    Required,
+    /// A failure only degrades the service.
+    Optional,
+}
+
+/// One dependency probed by the health endpoints, e.g. the database or the
+/// free disk space. Register implementations on the [`HealthRegistry`].
+pub trait HealthCheck: Send + Sync {
+    /// Stable `snake_case` name reported to clients.
+    fn name(&self) -> &'static str;
+
+    fn criticality(&self) -> Criticality {
+        Criticality::Required
+    }
+
+    /// How long [`check`](Self::check) may take before it counts as failed.
+    fn timeout(&self) -> Duration {
+        DEFAULT_CHECK_TIMEOUT
+    }
+
+    /// Resolves to an error describing the problem when the dependency is
+    /// unhealthy.
+    fn check(&self) -> CheckFuture<'_>;
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub criticality: Criticality,
+    pub latency: Duration,
+    /// Why the check failed; `None` when it passed.
+    pub error: Option<String>,
+}
+
+impl CheckResult {
+    pub fn is_healthy(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Health checks shared through `AppState`, run together by the health
+/// endpoints.
+#[derive(Clone, Default)]
+pub struct HealthRegistry {
+    checks: Vec<Arc<dyn HealthCheck>>,
+}
+
+impl std::fmt::Debug for HealthRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.checks.iter().map(|c| c.name()))
+            .finish()
+    }
+}
+
+impl HealthRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, check: impl HealthCheck + 'static) -> &mut Self {
+        self.checks.push(Arc::new(check));
+        self
+    }
+
+    /// Runs every check concurrently, each bounded by its own timeout, and
+    /// returns the results in registration order.
+    pub async fn run(&self) -> Vec<CheckResult> {
+        let handles = self
+            .checks
+            .iter()
+            .map(|check| {
+                let check = check.clone();
+                let span = tracing::info_span!("health_check", name = check.name());
+
+                tokio::spawn(async move { run_check(check.as_ref()).await }.instrument(span))
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(handles.len());
+
+        for (check, handle) in self.checks.iter().zip(handles) {
+            let result = handle.await.unwrap_or_else(|e| CheckResult {
+                name: check.name(),
+                criticality: check.criticality(),
+                latency: Duration::ZERO,
+                error: Some(format!("Check did not complete: {e}")),
+            });
+
+            results.push(result);
+        }
+
+        results
+    }
+}
+
+async fn run_check(check: &dyn HealthCheck) -> CheckResult {
+    let timeout = check.timeout();
+    let started = Instant::now();
+
+    let error = match tokio::time::timeout(timeout, check.check()).await {
+        Ok(Ok(())) => None,
+        Ok(Err(e)) => Some(e.to_string()),
+        Err(_) => Some(format!("Timed out after {}ms", timeout.as_millis())),
+    };
+
+    if let Some(error) = &error {
+        tracing::warn!(check = check.name(), %error, "Health check failed");
+    }
+
+    CheckResult {
+        name: check.name(),
+        criticality: check.criticality(),
+        latency: started.elapsed(),
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sleepy {
+        name: &'static str,
+        sleep: Duration,
+        fails: bool,
+    }
+
+    impl HealthCheck for Sleepy {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn criticality(&self) -> Criticality {
+            Criticality::Optional
+        }
+
+        fn timeout(&self) -> Duration {
+            Duration::from_millis(200)
+        }
+
+        fn check(&self) -> CheckFuture<'_> {
+            Box::pin(async move {
+                tokio::time::sleep(self.sleep).await;
+
+                if self.fails {
+                    anyhow::bail!("{} is down", self.name);
+                }
+
+                Ok(())
+            })
+        }
+    }
+
+    fn sleepy(name: &'static str, millis: u64, fails: bool) -> Sleepy {
+        Sleepy {
+            name,
+            sleep: Duration::from_millis(millis),
+            fails,
+        }
+    }
+
+    // Paused time auto-advances whenever the runtime is idle, so sleeps and
+    // timeouts resolve instantly and deterministically.
+    #[tokio::test(start_paused = true)]
+    async fn reports_each_check_in_registration_order() {
+        let mut registry = HealthRegistry::new();
+        registry
+            .register(sleepy("slow", 50, false))
+            .register(sleepy("broken", 0, true))
+            .register(sleepy("hung", 5_000, false));
+
+        let results = registry.run().await;
+        let names = results.iter().map(|r| r.name).collect::<Vec<_>>();
+
+        assert_eq!(names, ["slow", "broken", "hung"]);
+        assert!(results[0].is_healthy());
+        assert_eq!(results[0].criticality, Criticality::Optional);
+        assert_eq!(results[1].error.as_deref(), Some("broken is down"));
+        assert_eq!(results[2].error.as_deref(), Some("Timed out after 200ms"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn runs_checks_concurrently() {
+        let mut registry = HealthRegistry::new();
+        registry
+            .register(sleepy("a", 100, false))
+            .register(sleepy("b", 100, false))
+            .register(sleepy("c", 100, false));
+
+        let started = tokio::time::Instant::now();
+        registry.run().await;
+
+        // Run one after another, the checks would take 300ms.
+        assert_eq!(started.elapsed(), Duration::from_millis(100));
+    }
+}
@@ -1 +1,4 @@
+pub mod authorization;
 pub mod error;
+pub mod error_code;
+pub mod health;
@@ -0,0 +1,51 @@
+use anyhow::Context;
+use tracing::info;
+use validator::Validate;
+
+use crate::{
+    application::authorization::PermissionCache,
+    cli::CreateAdminArgs,
+    config::db::init_db,
+    data::env::Env,
+    infrastructure::security::password::PasswordHasher,
+    modules::{
+        user::{
+            domain::model::UserPayload, user_repository::UserRepository, user_service::UserService,
+        },
+        user_role::{
+            domain::model::UserRoleModel, user_role_repository::UserRoleRepository,
+            user_role_service::UserRoleService,
+        },
+    },
+};
+
+pub async fn create_admin(env: &Env, args: CreateAdminArgs) -> anyhow::Result<()> {
+    let db = init_db(env).await?;
+    let roles = UserRoleService::new(UserRoleRepository::new(db.clone()), PermissionCache::new());
+    let users = UserService::new(UserRepository::new(db), PasswordHasher::from_env(env)?);
+
+    let role = roles
+        .find_user_role_by_name(UserRoleModel::ADMIN)
+        .await?
+        .context("The admin role does not exist, run `migrate up` first")?;
+
+    let payload = UserPayload {
+        name: args.name.unwrap_or_else(|| args.username.clone()),
+        username: args.username,
+        password: args.password,
+        email: args.email,
+    };
+    payload.validate()?;
+
+    for login in [&payload.username, &payload.email] {
+        if users.find_credential_by_login(login).await?.is_some() {
+            anyhow::bail!("A user with username or email `{login}` already exists");
+        }
+    }
+
+    let user = users.create_user_with_role(payload, role.id).await?;
+
+    info!(user_id = user.id, "Created admin user `{}`", user.username);
+
+    Ok(())
+}
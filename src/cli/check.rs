@@ -0,0 +1,35 @@
+use anyhow::Context;
+
+use crate::{
+    config::{db::init_db, migration},
+    data::env::Env,
+    infrastructure::security::{jwt::JwtKeys, password::PasswordHasher},
+};
+
+/// Reports whether the process could serve traffic with the current
+/// environment. Variables are already parsed and validated by `init_env`, so
+/// this covers what only shows up when they are used.
+pub async fn run(env: &Env) -> anyhow::Result<()> {
+    PasswordHasher::from_env(env).context("Invalid password hashing parameters")?;
+    JwtKeys::from_env(env);
+    println!("{:<12}  ok", "environment");
+
+    let db = init_db(env)
+        .await
+        .context("Failed to connect to the database")?;
+    println!("{:<12}  ok", "database");
+
+    let pending = migration::migration_status(&db)
+        .await?
+        .iter()
+        .filter(|m| !m.applied)
+        .count();
+
+    if pending == 0 {
+        println!("{:<12}  ok", "migrations");
+    } else {
+        println!("{:<12}  {} pending", "migrations", pending);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use tracing::info;
+
+use crate::{
+    cli::MigrateAction,
+    config::{db::init_db, migration},
+    data::env::Env,
+};
+
+pub async fn run(env: &Env, action: MigrateAction) -> anyhow::Result<()> {
+    let db = init_db(env).await?;
+
+    match action {
+        MigrateAction::Up => {
+            migration::run_migrations(&db).await?;
+            info!("Database migrations applied");
+        }
+        MigrateAction::Down => match migration::revert_last_migration(&db).await? {
+            Some(version) => info!("Reverted migration {}", version),
+            None => info!("No migrations to revert"),
+        },
+        MigrateAction::Status => {
+            for m in migration::migration_status(&db).await? {
+                let state = if m.applied { "applied" } else { "pending" };
+                println!("{:>14}  {:<8}  {}", m.version, state, m.description);
+            }
+        }
+    }
+
+    Ok(())
+}
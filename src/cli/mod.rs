@@ -0,0 +1,91 @@
+use std::num::NonZeroU16;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+pub mod admin;
+pub mod check;
+pub mod migrate;
+pub mod purge;
+pub mod seed;
+
+/// Axum REST API server and the operational commands that go with it.
+#[derive(Debug, Parser)]
+#[command(version)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Start the HTTP server (the default when no command is given)
+    Serve,
+
+    /// Apply, revert or inspect database migrations
+    Migrate {
+        #[arg(value_enum, default_value_t = MigrateAction::Up)]
+        action: MigrateAction,
+    },
+
+    /// Insert baseline data that migrations do not own
+    Seed,
+
+    /// Create a user holding the admin role
+    CreateAdmin(CreateAdminArgs),
+
+    /// Permanently remove users soft-deleted longer ago than the retention period
+    PurgeDeletedUsers {
+        /// At least 1; a zero-day retention would purge every deleted user
+        #[arg(long)]
+        older_than_days: NonZeroU16,
+    },
+
+    /// Validate the environment and check database connectivity
+    CheckConfig,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum MigrateAction {
+    /// Apply all pending migrations
+    Up,
+    /// Revert the most recently applied migration
+    Down,
+    /// List every migration and whether it has been applied
+    Status,
+}
+
+#[derive(Debug, Args)]
+pub struct CreateAdminArgs {
+    #[arg(long)]
+    pub username: String,
+
+    #[arg(long)]
+    pub email: String,
+
+    /// Display name, defaults to the username
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Prefer the environment variable so the password stays out of shell history
+    #[arg(long, env = "ADMIN_PASSWORD", hide_env_values = true)]
+    pub password: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn purge(days: &str) -> Result<Option<Command>, clap::Error> {
+        Cli::try_parse_from(["app", "purge-deleted-users", "--older-than-days", days])
+            .map(|cli| cli.command)
+    }
+
+    #[test]
+    fn purge_requires_at_least_one_day_of_retention() {
+        assert!(purge("0").is_err());
+        assert!(matches!(
+            purge("1"),
+            Ok(Some(Command::PurgeDeletedUsers { older_than_days })) if older_than_days.get() == 1
+        ));
+    }
+}
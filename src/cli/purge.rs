@@ -0,0 +1,24 @@
+use std::num::NonZeroU16;
+
+use tracing::info;
+
+use crate::{
+    config::db::init_db,
+    data::env::Env,
+    infrastructure::security::password::PasswordHasher,
+    modules::user::{user_repository::UserRepository, user_service::UserService},
+};
+
+pub async fn purge_deleted_users(env: &Env, older_than_days: NonZeroU16) -> anyhow::Result<()> {
+    let db = init_db(env).await?;
+    let users = UserService::new(UserRepository::new(db), PasswordHasher::from_env(env)?);
+
+    let purged = users.purge_deleted_users(older_than_days).await?;
+
+    info!(
+        purged,
+        "Purged users deleted more than {} days ago", older_than_days
+    );
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use tracing::info;
+
+use crate::{
+    application::authorization::PermissionCache,
+    config::db::init_db,
+    data::env::Env,
+    modules::{
+        user::domain::policy::UserPermission,
+        user_role::{
+            domain::{
+                model::{UserRoleModel, UserRolePayload},
+                policy::UserRolePermission,
+            },
+            user_role_repository::UserRoleRepository,
+            user_role_service::UserRoleService,
+        },
+    },
+};
+
+/// Creates the read-only `member` role. Safe to run repeatedly: an existing
+/// role is left untouched so permissions edited through the API survive.
+pub async fn run(env: &Env) -> anyhow::Result<()> {
+    let db = init_db(env).await?;
+    let roles = UserRoleService::new(UserRoleRepository::new(db), PermissionCache::new());
+
+    if let Some(role) = roles.find_user_role_by_name(UserRoleModel::MEMBER).await? {
+        info!(role_id = role.id, "Role `{}` already exists", role.name);
+        return Ok(());
+    }
+
+    let role = roles
+        .create_user_role(UserRolePayload {
+            name: UserRoleModel::MEMBER.to_string(),
+        })
+        .await?;
+
+    roles
+        .replace_permissions(
+            role.id,
+            vec![
+                UserPermission::READ.to_string(),
+                UserRolePermission::READ.to_string(),
+            ],
+        )
+        .await?;
+
+    info!(role_id = role.id, "Seeded role `{}`", role.name);
+
+    Ok(())
+}
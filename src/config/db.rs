@@ -1,6 +1,8 @@
-use sqlx::{postgres::PgPoolOptions, Pool, Postgres};
+use std::time::Instant;
 
-use crate::data::env::Env;
+use sqlx::{Pool, Postgres, pool::PoolConnection, postgres::PgPoolOptions};
+
+use crate::{config::metrics::DB_POOL_ACQUIRE_WAIT_SECONDS, data::env::Env};
 
 const MAX_DB_CONNECTIONS: u32 = 5;
 
@@ -14,3 +16,15 @@ pub async fn init_db(env: &Env) -> anyhow::Result<DbPool> {
 
     Ok(pool)
 }
+
+/// Checks a connection out of the pool, recording the wait in the
+/// `db_pool_acquire_wait_seconds` histogram. Repositories run their queries
+/// on it rather than on the pool, which would acquire one untimed.
+pub async fn acquire(db: &DbPool) -> Result<PoolConnection<Postgres>, sqlx::Error> {
+    let started_at = Instant::now();
+    let conn = db.acquire().await;
+
+    metrics::histogram!(DB_POOL_ACQUIRE_WAIT_SECONDS).record(started_at.elapsed().as_secs_f64());
+
+    conn
+}
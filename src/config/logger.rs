@@ -1,7 +1,407 @@
-use tracing_subscriber::EnvFilter;
+use std::{fmt, str::FromStr};
 
-pub fn init_logger() {
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::from_default_env())
-        .init();
+use chrono::{SecondsFormat, Utc};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+    Resource,
+    trace::{Sampler, SdkTracerProvider},
+};
+use tracing::{
+    Event, Subscriber,
+    field::{Field, Visit},
+};
+use tracing_appender::{
+    non_blocking::WorkerGuard,
+    rolling::{RollingFileAppender, Rotation},
+};
+use tracing_subscriber::{
+    EnvFilter, Layer,
+    fmt::{
+        FmtContext, FormatEvent, FormatFields, FormattedFields, MakeWriter,
+        format::{JsonFields, Writer},
+    },
+    layer::SubscriberExt,
+    registry::LookupSpan,
+    util::SubscriberInitExt,
+};
+
+use crate::data::env::Env;
+
+const DEFAULT_LOG_FILE_PREFIX: &str = "server.log";
+const DEFAULT_SERVICE_NAME: &str = env!("CARGO_PKG_NAME");
+
+/// Output format of log lines, on stdout and in the log file alike.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// `tracing_subscriber`'s default single-line format.
+    #[default]
+    Full,
+    /// Multi-line, human-friendly output for local development.
+    Pretty,
+    /// Single line without span context.
+    Compact,
+    /// One JSON object per event, span fields flattened into it.
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "full" => Ok(LogFormat::Full),
+            "pretty" => Ok(LogFormat::Pretty),
+            "compact" => Ok(LogFormat::Compact),
+            "json" => Ok(LogFormat::Json),
+            other => Err(format!(
+                "unknown log format `{other}`, expected full, pretty, compact or json"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LogFormat::Full => "full",
+            LogFormat::Pretty => "pretty",
+            LogFormat::Compact => "compact",
+            LogFormat::Json => "json",
+        })
+    }
+}
+
+/// Keeps the background log writer and span exporter running. Dropping it
+/// flushes buffered log lines and pending spans, so hold it for the whole
+/// process.
+pub struct LoggerGuard {
+    _file: Option<WorkerGuard>,
+    tracer_provider: Option<SdkTracerProvider>,
+}
+
+impl Drop for LoggerGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.tracer_provider.take()
+            && let Err(e) = provider.shutdown()
+        {
+            eprintln!("Failed to flush pending spans: {e}");
+        }
+    }
+}
+
+/// Installs the global subscriber: stdout, plus a daily-rotated file in
+/// `LOG_DIR` and OTLP span export to `OTLP_ENDPOINT` when those are set.
+pub fn init_logger(env: &Env) -> anyhow::Result<LoggerGuard> {
+    let format = env.log_format.unwrap_or_default();
+
+    let (file, guard) = match &env.log_dir {
+        Some(dir) => {
+            let appender = RollingFileAppender::builder()
+                .rotation(Rotation::DAILY)
+                .filename_prefix(
+                    env.log_file_prefix
+                        .as_deref()
+                        .unwrap_or(DEFAULT_LOG_FILE_PREFIX),
+                )
+                .build(dir)?;
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+
+            (Some(fmt_layer(format, writer, false)), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    let tracer_provider = env
+        .otlp_endpoint
+        .as_deref()
+        .map(|endpoint| {
+            tracer_provider(
+                endpoint,
+                env.otlp_service_name
+                    .as_deref()
+                    .unwrap_or(DEFAULT_SERVICE_NAME),
+                env.otlp_sampling_ratio.unwrap_or(1.0),
+            )
+        })
+        .transpose()?;
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::from_default_env())
+        .with(fmt_layer(format, std::io::stdout, true))
+        .with(file)
+        .with(tracer_provider.as_ref().map(otlp_layer))
+        .try_init()?;
+
+    Ok(LoggerGuard {
+        _file: guard,
+        tracer_provider,
+    })
+}
+
+/// Batches spans to an OTLP/HTTP collector at `endpoint`, e.g.
+/// `http://localhost:4318`. Traces started upstream keep their sampling
+/// decision; new ones are sampled at `sampling_ratio`.
+fn tracer_provider(
+    endpoint: &str,
+    service_name: &str,
+    sampling_ratio: f64,
+) -> anyhow::Result<SdkTracerProvider> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            sampling_ratio,
+        ))))
+        .with_resource(
+            Resource::builder()
+                .with_service_name(service_name.to_string())
+                .build(),
+        )
+        .build();
+
+    Ok(provider)
+}
+
+fn otlp_layer<S>(provider: &SdkTracerProvider) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a> + Send + Sync,
+{
+    tracing_opentelemetry::layer()
+        .with_tracer(provider.tracer(DEFAULT_SERVICE_NAME))
+        .boxed()
+}
+
+fn fmt_layer<S, W>(format: LogFormat, writer: W, ansi: bool) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let layer = tracing_subscriber::fmt::layer()
+        .with_writer(writer)
+        .with_ansi(ansi);
+
+    match format {
+        LogFormat::Full => layer.boxed(),
+        LogFormat::Pretty => layer.pretty().boxed(),
+        LogFormat::Compact => layer.compact().boxed(),
+        LogFormat::Json => layer
+            .with_ansi(false)
+            .fmt_fields(JsonFields::new())
+            .event_format(FlatJson)
+            .boxed(),
+    }
+}
+
+/// JSON event format with the fields of every enclosing span, e.g. the
+/// `#[instrument]` arguments and the request id, merged into the top-level
+/// object so they can be indexed directly. Inner spans and the event itself
+/// win when names clash.
+struct FlatJson;
+
+impl<S, N> FormatEvent<S, N> for FlatJson
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let meta = event.metadata();
+        let mut object = serde_json::Map::new();
+
+        object.insert(
+            "timestamp".to_string(),
+            Utc::now()
+                .to_rfc3339_opts(SecondsFormat::Micros, true)
+                .into(),
+        );
+        object.insert("level".to_string(), meta.level().as_str().into());
+        object.insert("target".to_string(), meta.target().into());
+
+        if let Some(scope) = ctx.event_scope() {
+            let mut spans = Vec::new();
+
+            for span in scope.from_root() {
+                spans.push(serde_json::Value::from(span.name()));
+
+                let extensions = span.extensions();
+                let Some(fields) = extensions.get::<FormattedFields<N>>() else {
+                    continue;
+                };
+
+                if let Ok(serde_json::Value::Object(fields)) =
+                    serde_json::from_str::<serde_json::Value>(fields)
+                {
+                    object.extend(fields);
+                }
+            }
+
+            object.insert("spans".to_string(), spans.into());
+        }
+
+        event.record(&mut JsonVisitor(&mut object));
+
+        writeln!(writer, "{}", serde_json::Value::Object(object))
+    }
+}
+
+struct JsonVisitor<'a>(&'a mut serde_json::Map<String, serde_json::Value>);
+
+impl Visit for JsonVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}").into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex, mpsc},
+        thread,
+    };
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn parses_formats_case_insensitively() {
+        assert_eq!("JSON".parse::<LogFormat>(), Ok(LogFormat::Json));
+        assert_eq!("pretty".parse::<LogFormat>(), Ok(LogFormat::Pretty));
+        assert!("yaml".parse::<LogFormat>().is_err());
+    }
+
+    #[test]
+    fn json_flattens_span_fields_into_events() {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::registry().with(fmt_layer(
+            LogFormat::Json,
+            move || writer.clone(),
+            false,
+        ));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let outer = tracing::info_span!("request", request_id = "abc");
+            let _outer = outer.enter();
+            let inner = tracing::info_span!("find_user_by_id", id = 7);
+            let _inner = inner.enter();
+
+            tracing::info!(found = true, "User loaded");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let line: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+
+        assert_eq!(line["message"], "User loaded");
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["request_id"], "abc");
+        assert_eq!(line["id"], 7);
+        assert_eq!(line["found"], true);
+        assert_eq!(
+            line["spans"],
+            serde_json::json!(["request", "find_user_by_id"])
+        );
+    }
+
+    /// Stands in for an OTLP collector: accepts one request and reports its
+    /// request line and body size.
+    fn collector() -> (String, mpsc::Receiver<(String, usize)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .unwrap();
+
+            tx.send((request_line.trim().to_string(), body.len()))
+                .unwrap();
+        });
+
+        (endpoint, rx)
+    }
+
+    #[test]
+    fn exports_spans_to_the_otlp_endpoint() {
+        let (endpoint, requests) = collector();
+        let provider = tracer_provider(&endpoint, "test-service", 1.0).unwrap();
+        let subscriber = tracing_subscriber::registry().with(otlp_layer(&provider));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("db.query", db.statement = "SELECT 1").in_scope(|| {});
+        });
+        provider.shutdown().unwrap();
+
+        let (request_line, body_len) = requests.recv().unwrap();
+
+        assert_eq!(request_line, "POST /v1/traces HTTP/1.1");
+        assert!(body_len > 0);
+    }
 }
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use metrics::{Unit, describe_counter, describe_gauge, describe_histogram};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+
+// ===== HTTP =====
+pub const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+pub const HTTP_REQUEST_DURATION_SECONDS: &str = "http_request_duration_seconds";
+pub const HTTP_REQUESTS_IN_FLIGHT: &str = "http_requests_in_flight";
+
+// ===== DATABASE =====
+pub const DB_POOL_CONNECTIONS: &str = "db_pool_connections";
+pub const DB_POOL_MAX_CONNECTIONS: &str = "db_pool_max_connections";
+pub const DB_POOL_ACQUIRE_WAIT_SECONDS: &str = "db_pool_acquire_wait_seconds";
+
+// ===== SYSTEM =====
+pub const SYSTEM_CPU_USAGE_PERCENT: &str = "system_cpu_usage_percent";
+pub const SYSTEM_MEMORY_USED_BYTES: &str = "system_memory_used_bytes";
+pub const SYSTEM_MEMORY_TOTAL_BYTES: &str = "system_memory_total_bytes";
+
+const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// How often histogram samples are drained into their buckets.
+const UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Installs the global Prometheus recorder. Must be called from within the
+/// Tokio runtime, which runs the recorder's periodic upkeep.
+pub fn init_metrics() -> anyhow::Result<PrometheusHandle> {
+    let handle = recorder_builder()?.install_recorder()?;
+    describe_metrics();
+
+    let upkeep = handle.clone();
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(UPKEEP_INTERVAL).await;
+            upkeep.run_upkeep();
+        }
+    });
+
+    Ok(handle)
+}
+
+pub fn recorder_builder() -> anyhow::Result<PrometheusBuilder> {
+    let builder = PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full(HTTP_REQUEST_DURATION_SECONDS.to_string()),
+            LATENCY_BUCKETS,
+        )?
+        .set_buckets_for_metric(
+            Matcher::Full(DB_POOL_ACQUIRE_WAIT_SECONDS.to_string()),
+            LATENCY_BUCKETS,
+        )?;
+
+    Ok(builder)
+}
+
+fn describe_metrics() {
+    describe_counter!(
+        HTTP_REQUESTS_TOTAL,
+        "HTTP requests handled, by method, matched route and status"
+    );
+    describe_histogram!(
+        HTTP_REQUEST_DURATION_SECONDS,
+        Unit::Seconds,
+        "HTTP request latency, by method, matched route and status"
+    );
+    describe_gauge!(
+        HTTP_REQUESTS_IN_FLIGHT,
+        "HTTP requests currently being handled"
+    );
+    describe_gauge!(
+        DB_POOL_CONNECTIONS,
+        "Open database connections, by state (idle or active)"
+    );
+    describe_gauge!(
+        DB_POOL_MAX_CONNECTIONS,
+        "Maximum size of the database connection pool"
+    );
+    describe_histogram!(
+        DB_POOL_ACQUIRE_WAIT_SECONDS,
+        Unit::Seconds,
+        "Time queries waited to acquire a pooled connection"
+    );
+    describe_gauge!(SYSTEM_CPU_USAGE_PERCENT, "CPU usage, by logical core");
+    describe_gauge!(SYSTEM_MEMORY_USED_BYTES, Unit::Bytes, "Used system memory");
+    describe_gauge!(
+        SYSTEM_MEMORY_TOTAL_BYTES,
+        Unit::Bytes,
+        "Total system memory"
+    );
+}
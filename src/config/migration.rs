@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use sqlx::migrate::{Migrate, Migrator};
+
+use crate::config::db::DbPool;
+
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+#[derive(Debug)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub applied: bool,
+}
+
+pub async fn run_migrations(db: &DbPool) -> anyhow::Result<()> {
+    MIGRATOR.run(db).await?;
+
+    Ok(())
+}
+
+/// Reverts the most recently applied migration, returning its version.
+pub async fn revert_last_migration(db: &DbPool) -> anyhow::Result<Option<i64>> {
+    let mut applied = applied_versions(db).await?.into_iter().collect::<Vec<_>>();
+    applied.sort_unstable();
+
+    let Some(last) = applied.pop() else {
+        return Ok(None);
+    };
+
+    let target = applied.last().copied().unwrap_or(0);
+    MIGRATOR.undo(db, target).await?;
+
+    Ok(Some(last))
+}
+
+pub async fn migration_status(db: &DbPool) -> anyhow::Result<Vec<MigrationStatus>> {
+    let applied = applied_versions(db).await?;
+
+    let status = MIGRATOR
+        .iter()
+        .filter(|m| m.migration_type.is_up_migration())
+        .map(|m| MigrationStatus {
+            version: m.version,
+            description: m.description.to_string(),
+            applied: applied.contains(&m.version),
+        })
+        .collect();
+
+    Ok(status)
+}
+
+async fn applied_versions(db: &DbPool) -> anyhow::Result<HashSet<i64>> {
+    let mut conn = db.acquire().await?;
+
+    conn.ensure_migrations_table().await?;
+    let applied = conn.list_applied_migrations().await?;
+
+    Ok(applied.into_iter().map(|m| m.version).collect())
+}
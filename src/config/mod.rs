@@ -1,2 +1,4 @@
 pub mod db;
 pub mod logger;
+pub mod metrics;
+pub mod migration;
@@ -6,6 +6,8 @@ use std::collections::HashMap;
 use std::env;
 use validator::Validate;
 
+use crate::config::logger::LogFormat;
+
 #[serde_as]
 #[derive(Debug, Deserialize, Validate)]
 pub struct Env {
@@ -16,6 +18,90 @@ pub struct Env {
     #[serde(rename = "DATABASE_URL")]
     #[serde_as(as = "DisplayFromStr")]
     pub database_url: String,
+
+    #[serde(rename = "AUTO_MIGRATE", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub auto_migrate: Option<bool>,
+
+    #[serde(rename = "PROBLEM_DETAILS", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub problem_details: Option<bool>,
+
+    // ===== LOGGING =====
+    #[serde(rename = "LOG_FORMAT", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub log_format: Option<LogFormat>,
+
+    #[serde(rename = "LOG_DIR", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub log_dir: Option<String>,
+
+    #[serde(rename = "LOG_FILE_PREFIX", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub log_file_prefix: Option<String>,
+
+    // ===== TRACING =====
+    #[serde(rename = "OTLP_ENDPOINT", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub otlp_endpoint: Option<String>,
+
+    #[serde(rename = "OTLP_SERVICE_NAME", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub otlp_service_name: Option<String>,
+
+    #[serde(rename = "OTLP_SAMPLING_RATIO", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub otlp_sampling_ratio: Option<f64>,
+
+    // ===== METRICS =====
+    #[serde(rename = "METRICS_PORT", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub metrics_port: Option<u16>,
+
+    // ===== SYSTEM SAMPLER =====
+    #[serde(rename = "SYSTEM_SAMPLE_INTERVAL", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 1))]
+    pub system_sample_interval: Option<u64>,
+
+    // ===== HEALTH =====
+    #[serde(rename = "HEALTH_MIN_FREE_DISK_PERCENT", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub health_min_free_disk_percent: Option<f64>,
+
+    // ===== JWT =====
+    #[serde(rename = "JWT_SECRET")]
+    #[serde_as(as = "DisplayFromStr")]
+    #[validate(length(min = 32))]
+    pub jwt_secret: String,
+
+    #[serde(rename = "JWT_EXPIRATION", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 1))]
+    pub jwt_expiration: Option<i64>,
+
+    #[serde(rename = "JWT_REFRESH_EXPIRATION", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 1))]
+    pub jwt_refresh_expiration: Option<i64>,
+
+    // ===== PASSWORD HASHING =====
+    #[serde(rename = "PASSWORD_HASH_MEMORY_COST", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 8))]
+    pub password_hash_memory_cost: Option<u32>,
+
+    #[serde(rename = "PASSWORD_HASH_ITERATIONS", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 1))]
+    pub password_hash_iterations: Option<u32>,
+
+    #[serde(rename = "PASSWORD_HASH_PARALLELISM", default)]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 1))]
+    pub password_hash_parallelism: Option<u32>,
 }
 
 pub fn init_env() -> Env {
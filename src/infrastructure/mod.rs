@@ -1 +1,3 @@
+pub mod security;
 pub mod sql;
+pub mod system;
@@ -0,0 +1,129 @@
+use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+use crate::data::env::Env;
+
+const DEFAULT_ACCESS_TOKEN_TTL_SECS: i64 = 900;
+const DEFAULT_REFRESH_TOKEN_TTL_SECS: i64 = 30 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessClaims {
+    /// User id.
+    pub sub: i32,
+    /// Role name from `user_roles`, if the user has one.
+    pub role: Option<String>,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+#[derive(Debug)]
+pub struct AccessToken {
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+pub struct JwtKeys {
+    encoding: EncodingKey,
+    decoding: DecodingKey,
+    access_ttl: Duration,
+    refresh_ttl: Duration,
+}
+
+impl std::fmt::Debug for JwtKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtKeys")
+            .field("access_ttl", &self.access_ttl)
+            .field("refresh_ttl", &self.refresh_ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl JwtKeys {
+    pub fn new(secret: &[u8], access_ttl: Duration, refresh_ttl: Duration) -> Self {
+        Self {
+            encoding: EncodingKey::from_secret(secret),
+            decoding: DecodingKey::from_secret(secret),
+            access_ttl,
+            refresh_ttl,
+        }
+    }
+
+    pub fn from_env(env: &Env) -> Self {
+        Self::new(
+            env.jwt_secret.as_bytes(),
+            Duration::seconds(env.jwt_expiration.unwrap_or(DEFAULT_ACCESS_TOKEN_TTL_SECS)),
+            Duration::seconds(
+                env.jwt_refresh_expiration
+                    .unwrap_or(DEFAULT_REFRESH_TOKEN_TTL_SECS),
+            ),
+        )
+    }
+
+    /// Lifetime of the opaque refresh tokens issued alongside access tokens.
+    pub fn refresh_ttl(&self) -> Duration {
+        self.refresh_ttl
+    }
+
+    pub fn issue(&self, user_id: i32, role: Option<String>) -> anyhow::Result<AccessToken> {
+        let issued_at = Utc::now();
+        let expires_at = issued_at + self.access_ttl;
+
+        let claims = AccessClaims {
+            sub: user_id,
+            role,
+            iat: issued_at.timestamp(),
+            exp: expires_at.timestamp(),
+        };
+
+        let token = jsonwebtoken::encode(&Header::new(Algorithm::HS256), &claims, &self.encoding)?;
+
+        Ok(AccessToken { token, expires_at })
+    }
+
+    pub fn verify(&self, token: &str) -> anyhow::Result<AccessClaims> {
+        let validation = Validation::new(Algorithm::HS256);
+
+        let data = jsonwebtoken::decode::<AccessClaims>(token, &self.decoding, &validation)?;
+
+        Ok(data.claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issued_token_round_trips() {
+        let keys = JwtKeys::new(b"test-secret", Duration::minutes(5), Duration::days(1));
+
+        let issued = keys.issue(42, Some("admin".into())).unwrap();
+        let claims = keys.verify(&issued.token).unwrap();
+
+        assert_eq!(claims.sub, 42);
+        assert_eq!(claims.role.as_deref(), Some("admin"));
+        assert_eq!(claims.exp, issued.expires_at.timestamp());
+    }
+
+    #[test]
+    fn token_signed_with_other_secret_is_rejected() {
+        let issued = JwtKeys::new(b"secret-a", Duration::minutes(5), Duration::days(1))
+            .issue(1, None)
+            .unwrap();
+
+        let keys = JwtKeys::new(b"secret-b", Duration::minutes(5), Duration::days(1));
+
+        assert!(keys.verify(&issued.token).is_err());
+    }
+
+    #[test]
+    fn expired_token_is_rejected() {
+        let keys = JwtKeys::new(b"test-secret", Duration::minutes(-5), Duration::days(1));
+
+        let issued = keys.issue(1, None).unwrap();
+
+        assert!(keys.verify(&issued.token).is_err());
+    }
+}
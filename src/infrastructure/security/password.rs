@@ -0,0 +1,183 @@
+use std::sync::{Arc, OnceLock};
+
+use argon2::{
+    Algorithm, Argon2, Params, PasswordHasher as _, PasswordVerifier as _, Version,
+    password_hash::{PasswordHash, SaltString, rand_core::OsRng},
+};
+
+use crate::data::env::Env;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordVerification {
+    Invalid,
+    Valid,
+    /// The password matched, but the stored hash was produced with
+    /// different parameters and should be replaced.
+    ValidNeedsRehash,
+}
+
+#[derive(Debug, Clone)]
+pub struct PasswordHasher {
+    params: Params,
+    /// Hash of a throwaway password with the current parameters, verified
+    /// against when no account matches, so lookups cost the same either way.
+    dummy_hash: Arc<OnceLock<String>>,
+}
+
+impl PasswordHasher {
+    pub fn new(memory_cost: u32, iterations: u32, parallelism: u32) -> anyhow::Result<Self> {
+        let params = Params::new(memory_cost, iterations, parallelism, None)
+            .map_err(|e| anyhow::anyhow!("Invalid argon2 parameters: {e}"))?;
+
+        Ok(Self {
+            params,
+            dummy_hash: Arc::default(),
+        })
+    }
+
+    pub fn from_env(env: &Env) -> anyhow::Result<Self> {
+        Self::new(
+            env.password_hash_memory_cost
+                .unwrap_or(Params::DEFAULT_M_COST),
+            env.password_hash_iterations
+                .unwrap_or(Params::DEFAULT_T_COST),
+            env.password_hash_parallelism
+                .unwrap_or(Params::DEFAULT_P_COST),
+        )
+    }
+
+    fn argon2(&self) -> Argon2<'_> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
+    }
+
+    /// Hashes `password` into a PHC string (`$argon2id$v=19$m=...`).
+    pub fn hash(&self, password: &str) -> anyhow::Result<String> {
+        let salt = SaltString::generate(&mut OsRng);
+
+        let hash = self
+            .argon2()
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|e| anyhow::anyhow!("Failed to hash password: {e}"))?;
+
+        Ok(hash.to_string())
+    }
+
+    pub fn verify(&self, password: &str, phc: &str) -> anyhow::Result<PasswordVerification> {
+        let parsed =
+            PasswordHash::new(phc).map_err(|e| anyhow::anyhow!("Malformed password hash: {e}"))?;
+
+        // Argon2 reads the algorithm and cost from the PHC string itself,
+        // so hashes created with older parameters still verify.
+        match self.argon2().verify_password(password.as_bytes(), &parsed) {
+            Ok(()) if self.needs_rehash(&parsed) => Ok(PasswordVerification::ValidNeedsRehash),
+            Ok(()) => Ok(PasswordVerification::Valid),
+            Err(argon2::password_hash::Error::Password) => Ok(PasswordVerification::Invalid),
+            Err(e) => Err(anyhow::anyhow!("Failed to verify password: {e}")),
+        }
+    }
+
+    /// Spends the same time as [`verify`](Self::verify) on a real account,
+    /// for callers that found no hash to check `password` against.
+    pub fn verify_dummy(&self, password: &str) -> anyhow::Result<()> {
+        let phc = match self.dummy_hash.get() {
+            Some(phc) => phc,
+            None => {
+                let phc = self.hash("dummy-password")?;
+                self.dummy_hash.get_or_init(|| phc)
+            }
+        };
+
+        self.verify(password, phc)?;
+
+        Ok(())
+    }
+
+    fn needs_rehash(&self, parsed: &PasswordHash<'_>) -> bool {
+        if parsed.algorithm != Algorithm::Argon2id.ident()
+            || parsed.version != Some(Version::V0x13.into())
+        {
+            return true;
+        }
+
+        match Params::try_from(parsed) {
+            Ok(current) => {
+                current.m_cost() != self.params.m_cost()
+                    || current.t_cost() != self.params.t_cost()
+                    || current.p_cost() != self.params.p_cost()
+            }
+            Err(_) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hasher(memory_cost: u32, iterations: u32) -> PasswordHasher {
+        PasswordHasher::new(memory_cost, iterations, 1).unwrap()
+    }
+
+    #[test]
+    fn hash_is_argon2id_phc_string() {
+        let phc = hasher(8, 1).hash("secret").unwrap();
+
+        assert!(phc.starts_with("$argon2id$v=19$m=8,t=1,p=1$"));
+    }
+
+    #[test]
+    fn hash_uses_random_salt() {
+        let h = hasher(8, 1);
+
+        assert_ne!(h.hash("secret").unwrap(), h.hash("secret").unwrap());
+    }
+
+    #[test]
+    fn verify_matching_password() {
+        let h = hasher(8, 1);
+        let phc = h.hash("secret").unwrap();
+
+        assert_eq!(
+            h.verify("secret", &phc).unwrap(),
+            PasswordVerification::Valid
+        );
+    }
+
+    #[test]
+    fn verify_wrong_password() {
+        let h = hasher(8, 1);
+        let phc = h.hash("secret").unwrap();
+
+        assert_eq!(
+            h.verify("not-secret", &phc).unwrap(),
+            PasswordVerification::Invalid
+        );
+    }
+
+    #[test]
+    fn verify_flags_rehash_when_params_change() {
+        let phc = hasher(8, 1).hash("secret").unwrap();
+
+        assert_eq!(
+            hasher(16, 2).verify("secret", &phc).unwrap(),
+            PasswordVerification::ValidNeedsRehash
+        );
+    }
+
+    #[test]
+    fn verify_dummy_reuses_one_hash_with_current_params() {
+        let h = hasher(8, 1);
+
+        h.verify_dummy("secret").unwrap();
+        let phc = h.dummy_hash.get().unwrap().clone();
+        h.clone().verify_dummy("other").unwrap();
+
+        assert!(phc.starts_with("$argon2id$v=19$m=8,t=1,p=1$"));
+        assert_eq!(h.dummy_hash.get(), Some(&phc));
+    }
+
+    #[test]
+    fn verify_rejects_malformed_hash() {
+        assert!(hasher(8, 1).verify("secret", "plaintext").is_err());
+    }
+}
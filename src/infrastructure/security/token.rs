@@ -0,0 +1,44 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+const OPAQUE_TOKEN_BYTES: usize = 32;
+
+/// A random, URL-safe opaque token (hex encoded).
+pub fn generate_opaque_token() -> String {
+    let mut bytes = [0u8; OPAQUE_TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+
+    to_hex(&bytes)
+}
+
+/// SHA-256 of an opaque token; only this digest is ever stored.
+pub fn hash_opaque_token(token: &str) -> String {
+    to_hex(&Sha256::digest(token.as_bytes()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_tokens_are_unique_hex() {
+        let a = generate_opaque_token();
+        let b = generate_opaque_token();
+
+        assert_eq!(a.len(), OPAQUE_TOKEN_BYTES * 2);
+        assert!(a.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn hash_is_stable_sha256() {
+        assert_eq!(
+            hash_opaque_token("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
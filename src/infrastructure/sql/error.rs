@@ -0,0 +1,151 @@
+use std::fmt;
+
+use sqlx::{error::ErrorKind, postgres::PgDatabaseError};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    Unique,
+    ForeignKey,
+    Check,
+    NotNull,
+}
+
+impl fmt::Display for ConstraintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ConstraintKind::Unique => "unique",
+            ConstraintKind::ForeignKey => "foreign key",
+            ConstraintKind::Check => "check",
+            ConstraintKind::NotNull => "not-null",
+        })
+    }
+}
+
+/// A write rejected by a table constraint, attributed to the offending column.
+#[derive(Debug, Clone, Error)]
+#[error("{kind} constraint violated on `{field}`")]
+pub struct ConstraintViolation {
+    pub kind: ConstraintKind,
+    pub field: String,
+    pub constraint: Option<String>,
+}
+
+/// Database error as seen by repositories. Constraint violations are split out
+/// so the application layer can report them as client errors instead of 500s.
+///
+/// Repositories opt in with `.map_err(DbError::from)?` on writes that can hit
+/// a constraint; anything else keeps propagating as a plain `sqlx::Error`.
+#[derive(Debug, Error)]
+pub enum DbError {
+    #[error(transparent)]
+    Constraint(ConstraintViolation),
+
+    #[error(transparent)]
+    Query(sqlx::Error),
+}
+
+impl From<sqlx::Error> for DbError {
+    fn from(err: sqlx::Error) -> Self {
+        match classify(&err) {
+            Some(violation) => DbError::Constraint(violation),
+            None => DbError::Query(err),
+        }
+    }
+}
+
+fn classify(err: &sqlx::Error) -> Option<ConstraintViolation> {
+    let db_err = err.as_database_error()?;
+
+    let kind = match db_err.kind() {
+        ErrorKind::UniqueViolation => ConstraintKind::Unique,
+        ErrorKind::ForeignKeyViolation => ConstraintKind::ForeignKey,
+        ErrorKind::CheckViolation => ConstraintKind::Check,
+        ErrorKind::NotNullViolation => ConstraintKind::NotNull,
+        _ => return None,
+    };
+
+    let pg_err = db_err.try_downcast_ref::<PgDatabaseError>();
+    let table = pg_err.and_then(PgDatabaseError::table);
+    let constraint = db_err.constraint().map(str::to_string);
+
+    // Postgres reports the column for NOT NULL violations only; every other
+    // kind has to be traced back through the constraint name.
+    let field = pg_err
+        .and_then(PgDatabaseError::column)
+        .map(str::to_string)
+        .or_else(|| {
+            constraint
+                .as_deref()
+                .map(|name| field_from_constraint(name, table))
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+
+    Some(ConstraintViolation {
+        kind,
+        field,
+        constraint,
+    })
+}
+
+/// Recovers the column from Postgres' default constraint naming scheme,
+/// `<table>_<column>_<suffix>`, e.g. `users_email_key` -> `email`. Whatever
+/// part of the scheme is missing is simply left in place.
+fn field_from_constraint(constraint: &str, table: Option<&str>) -> String {
+    if constraint
+        .strip_suffix("_pkey")
+        .is_some_and(|t| Some(t) == table)
+    {
+        return "id".to_string();
+    }
+
+    let without_table = table
+        .and_then(|t| constraint.strip_prefix(t))
+        .and_then(|rest| rest.strip_prefix('_'))
+        .unwrap_or(constraint);
+
+    ["_key", "_fkey", "_check"]
+        .iter()
+        .find_map(|suffix| without_table.strip_suffix(suffix))
+        .filter(|field| !field.is_empty())
+        .unwrap_or(without_table)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_table_and_suffix() {
+        assert_eq!(
+            field_from_constraint("users_email_key", Some("users")),
+            "email"
+        );
+        assert_eq!(
+            field_from_constraint("users_role_id_fkey", Some("users")),
+            "role_id"
+        );
+        assert_eq!(
+            field_from_constraint("users_status_check", Some("users")),
+            "status"
+        );
+    }
+
+    #[test]
+    fn maps_primary_key_to_id() {
+        assert_eq!(field_from_constraint("users_pkey", Some("users")), "id");
+    }
+
+    #[test]
+    fn keeps_names_outside_the_naming_scheme() {
+        assert_eq!(
+            field_from_constraint("unique_login", Some("users")),
+            "unique_login"
+        );
+        assert_eq!(
+            field_from_constraint("user_roles_name_key", None),
+            "user_roles_name"
+        );
+    }
+}
@@ -1,3 +1,5 @@
+pub mod error;
 pub mod filter;
 pub mod order;
 pub mod pagination;
+pub mod trace;
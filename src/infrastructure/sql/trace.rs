@@ -0,0 +1,16 @@
+use sqlx::{Postgres, QueryBuilder};
+use tracing::Span;
+
+/// Span for one execution of `qb`, exported with the SQL text (bind
+/// parameters stay as `$n` placeholders, so values never leave the process).
+///
+/// Build it before `qb.build()` borrows the builder, then attach it with
+/// `.instrument(span)` on the fetch/execute future.
+pub fn query_span(qb: &QueryBuilder<'_, Postgres>) -> Span {
+    tracing::info_span!(
+        "db.query",
+        otel.kind = "client",
+        db.system = "postgresql",
+        db.statement = qb.sql(),
+    )
+}
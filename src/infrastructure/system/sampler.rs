@@ -0,0 +1,292 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use sysinfo::{Pid, ProcessesToUpdate, System};
+use tokio::task::JoinHandle;
+
+const ONE_MINUTE: Duration = Duration::from_secs(60);
+const FIVE_MINUTES: Duration = Duration::from_secs(300);
+/// Missed ticks tolerated before the sampler is reported as stale.
+const STALE_AFTER_INTERVALS: u32 = 3;
+
+#[derive(Debug, Clone, Default)]
+pub struct CpuSample {
+    pub model: String,
+    pub usage: f32,
+}
+
+/// One reading of the host and of this process.
+#[derive(Debug, Clone, Default)]
+pub struct SystemSample {
+    pub sampled_at: Option<DateTime<Utc>>,
+    pub cpus: Vec<CpuSample>,
+    /// Usage across all cores, in percent.
+    pub cpu_usage: f32,
+    pub memory_used: u64,
+    pub memory_total: u64,
+    /// This process' CPU usage, in percent of one core.
+    pub process_cpu_usage: f32,
+    /// This process' resident memory, in bytes.
+    pub process_memory: u64,
+}
+
+/// Mean of the samples taken over the last minute and five minutes.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RollingAverage {
+    pub avg_1m: f64,
+    pub avg_5m: f64,
+}
+
+/// The latest sample plus rolling averages, as served by the health endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct SystemStats {
+    pub latest: SystemSample,
+    pub cpu_usage: RollingAverage,
+    pub memory_used: RollingAverage,
+}
+
+/// Timestamped values kept for the longest averaging window.
+#[derive(Debug, Default)]
+struct RollingWindow {
+    samples: VecDeque<(Instant, f64)>,
+}
+
+impl RollingWindow {
+    fn push(&mut self, at: Instant, value: f64) {
+        self.samples.push_back((at, value));
+
+        while let Some((oldest, _)) = self.samples.front() {
+            if at.duration_since(*oldest) <= FIVE_MINUTES {
+                break;
+            }
+            self.samples.pop_front();
+        }
+    }
+
+    fn average(&self, now: Instant, window: Duration) -> f64 {
+        let (sum, count) = self
+            .samples
+            .iter()
+            .rev()
+            .take_while(|(at, _)| now.duration_since(*at) <= window)
+            .fold((0.0, 0u32), |(sum, count), (_, value)| {
+                (sum + value, count + 1)
+            });
+
+        if count == 0 {
+            0.0
+        } else {
+            sum / f64::from(count)
+        }
+    }
+
+    fn rolling(&self, now: Instant) -> RollingAverage {
+        RollingAverage {
+            avg_1m: self.average(now, ONE_MINUTE),
+            avg_5m: self.average(now, FIVE_MINUTES),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SamplerState {
+    stats: SystemStats,
+    interval: Option<Duration>,
+    last_sampled: Option<Instant>,
+    cpu_usage: RollingWindow,
+    memory_used: RollingWindow,
+}
+
+impl SamplerState {
+    fn record(&mut self, at: Instant, sample: SystemSample) {
+        self.cpu_usage.push(at, f64::from(sample.cpu_usage));
+        self.memory_used.push(at, sample.memory_used as f64);
+        self.last_sampled = Some(at);
+
+        self.stats = SystemStats {
+            latest: sample,
+            cpu_usage: self.cpu_usage.rolling(at),
+            memory_used: self.memory_used.rolling(at),
+        };
+    }
+
+    fn is_stale(&self, now: Instant) -> bool {
+        match (self.interval, self.last_sampled) {
+            (Some(interval), Some(at)) => now.duration_since(at) > interval * STALE_AFTER_INTERVALS,
+            _ => true,
+        }
+    }
+}
+
+/// CPU, memory and process stats, refreshed by a background task and shared
+/// through `AppState`, so readers never have to wait for a CPU measurement.
+#[derive(Debug, Clone, Default)]
+pub struct SystemSampler {
+    state: Arc<RwLock<SamplerState>>,
+}
+
+impl SystemSampler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts sampling every `interval`. CPU usage is a difference between
+    /// two refreshes, so the first figures appear after one interval.
+    pub fn spawn(&self, interval: Duration) -> JoinHandle<()> {
+        let sampler = self.clone();
+        let interval = interval.max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        self.state
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .interval = Some(interval);
+
+        tokio::spawn(async move {
+            let pid = sysinfo::get_current_pid().ok();
+
+            // sysinfo reads `/proc` synchronously, so every refresh runs on
+            // the blocking pool rather than stalling a runtime worker.
+            let Ok(mut sys) = tokio::task::spawn_blocking(|| {
+                let mut sys = System::new();
+                sys.refresh_cpu_all();
+                sys
+            })
+            .await
+            else {
+                return;
+            };
+
+            let mut ticker = tokio::time::interval(interval);
+            // The first tick completes immediately; skip it so CPU usage is
+            // measured over a full interval.
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+
+                // A panicking refresh ends the task, which `is_stale` reports.
+                let Ok((refreshed, sample)) = tokio::task::spawn_blocking(move || {
+                    let sample = sample(&mut sys, pid);
+                    (sys, sample)
+                })
+                .await
+                else {
+                    return;
+                };
+
+                sys = refreshed;
+                sampler
+                    .state
+                    .write()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .record(Instant::now(), sample);
+            }
+        })
+    }
+
+    pub fn stats(&self) -> SystemStats {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .stats
+            .clone()
+    }
+
+    /// Whether no sample has been taken within the last few intervals, e.g.
+    /// before the first tick or after the sampling task died.
+    pub fn is_stale(&self) -> bool {
+        self.state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_stale(Instant::now())
+    }
+}
+
+fn sample(sys: &mut System, pid: Option<Pid>) -> SystemSample {
+    sys.refresh_cpu_usage();
+    sys.refresh_memory();
+
+    if let Some(pid) = pid {
+        sys.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    }
+    let process = pid.and_then(|pid| sys.process(pid));
+
+    SystemSample {
+        sampled_at: Some(Utc::now()),
+        cpus: sys
+            .cpus()
+            .iter()
+            .map(|c| CpuSample {
+                model: c.brand().to_string(),
+                usage: c.cpu_usage(),
+            })
+            .collect(),
+        cpu_usage: sys.global_cpu_usage(),
+        memory_used: sys.used_memory(),
+        memory_total: sys.total_memory(),
+        process_cpu_usage: process.map_or(0.0, |p| p.cpu_usage()),
+        process_memory: process.map_or(0, |p| p.memory()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(cpu_usage: f32) -> SystemSample {
+        SystemSample {
+            cpu_usage,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn averages_over_each_window() {
+        let start = Instant::now();
+        let mut state = SamplerState::default();
+
+        // One sample per minute: 10, 20, 30, 40, 50, 60.
+        for minute in 0..6u32 {
+            let at = start + ONE_MINUTE * minute;
+            state.record(at, sample(10.0 * (minute + 1) as f32));
+        }
+
+        let stats = state.stats;
+
+        assert_eq!(stats.latest.cpu_usage, 60.0);
+        // The last minute includes the samples at 4 and 5 minutes.
+        assert_eq!(stats.cpu_usage.avg_1m, 55.0);
+        // The first sample is exactly five minutes old and still counts.
+        assert_eq!(stats.cpu_usage.avg_5m, 35.0);
+    }
+
+    #[test]
+    fn drops_samples_older_than_the_longest_window() {
+        let start = Instant::now();
+        let mut window = RollingWindow::default();
+
+        window.push(start, 100.0);
+        window.push(start + FIVE_MINUTES + Duration::from_secs(1), 0.0);
+
+        assert_eq!(window.samples.len(), 1);
+    }
+
+    #[test]
+    fn is_stale_until_sampled_and_after_missed_ticks() {
+        let start = Instant::now();
+        let interval = Duration::from_secs(5);
+        let mut state = SamplerState {
+            interval: Some(interval),
+            ..Default::default()
+        };
+
+        assert!(state.is_stale(start));
+
+        state.record(start, sample(0.0));
+        assert!(!state.is_stale(start + interval * STALE_AFTER_INTERVALS));
+        assert!(state.is_stale(start + interval * (STALE_AFTER_INTERVALS + 1)));
+    }
+}
@@ -1,31 +1,107 @@
-use std::time::Instant;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
+use axum::middleware;
+use clap::Parser;
 use tower_http::{compression::CompressionLayer, trace::TraceLayer};
 use tracing::info;
 
+use crate::cli::{Cli, Command};
+
 pub mod application;
+pub mod cli;
 pub mod config;
 pub mod data;
 pub mod infrastructure;
 pub mod modules;
 pub mod presentation;
 
+const DEFAULT_SYSTEM_SAMPLE_INTERVAL: u64 = 5;
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
     let env = data::env::init_env();
 
-    config::logger::init_logger();
+    let _log_guard = config::logger::init_logger(&env)?;
+
+    match cli.command.unwrap_or(Command::Serve) {
+        Command::Serve => serve(env).await,
+        Command::Migrate { action } => cli::migrate::run(&env, action).await,
+        Command::Seed => cli::seed::run(&env).await,
+        Command::CreateAdmin(args) => cli::admin::create_admin(&env, args).await,
+        Command::PurgeDeletedUsers { older_than_days } => {
+            cli::purge::purge_deleted_users(&env, older_than_days).await
+        }
+        Command::CheckConfig => cli::check::run(&env).await,
+    }
+}
+
+async fn serve(env: data::env::Env) -> anyhow::Result<()> {
     let db = config::db::init_db(&env).await?;
 
+    if env.auto_migrate.unwrap_or(false) {
+        config::migration::run_migrations(&db).await?;
+        info!("Database migrations applied");
+    }
+
+    let password_hasher = infrastructure::security::password::PasswordHasher::from_env(&env)?;
+    let jwt = infrastructure::security::jwt::JwtKeys::from_env(&env);
+    let policies = Arc::new(presentation::policy::create_policy_registry());
+
+    let system = infrastructure::system::sampler::SystemSampler::new();
+    system.spawn(Duration::from_secs(
+        env.system_sample_interval
+            .unwrap_or(DEFAULT_SYSTEM_SAMPLE_INTERVAL),
+    ));
+
+    let health = Arc::new(presentation::health::create_health_registry(
+        &db, &system, &env,
+    ));
+
     let state = presentation::state::AppState {
         started_at: Instant::now(),
+        startup_complete: Arc::default(),
         db,
+        password_hasher,
+        jwt,
+        policies,
+        permissions: application::authorization::PermissionCache::new(),
+        metrics: config::metrics::init_metrics()?,
+        system,
+        health,
     };
 
-    let app = presentation::router::create_router()
+    let error_format = presentation::problem::ErrorFormat::from_env(&env);
+
+    let router = match env.metrics_port {
+        Some(port) => {
+            serve_metrics(port, state.clone()).await?;
+            presentation::router::create_router(&state)
+        }
+        None => presentation::router::create_router(&state)
+            .merge(presentation::router::create_metrics_router()),
+    };
+
+    let app = router
+        .layer(middleware::from_fn_with_state(
+            error_format,
+            presentation::middleware::problem::problem_details,
+        ))
+        .layer(middleware::from_fn(
+            presentation::middleware::metrics::track_metrics,
+        ))
+        .layer(middleware::from_fn(
+            presentation::middleware::trace::record_route,
+        ))
         .with_state(state)
         .layer(CompressionLayer::new())
-        .layer(TraceLayer::new_for_http());
+        .layer(TraceLayer::new_for_http().make_span_with(presentation::trace::make_span))
+        .layer(middleware::from_fn(
+            presentation::middleware::request_id::request_id,
+        ));
 
     let addr = format!("0.0.0.0:{}", env.port);
     info!("🚀 Server running on {}", addr);
@@ -35,3 +111,21 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Serves `/metrics` on its own port, e.g. one only reachable from inside
+/// the cluster.
+async fn serve_metrics(port: u16, state: presentation::state::AppState) -> anyhow::Result<()> {
+    let app = presentation::router::create_metrics_router().with_state(state);
+
+    let addr = format!("0.0.0.0:{}", port);
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    info!("📈 Metrics served on {}", addr);
+
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app).await {
+            tracing::error!("Metrics server failed: {:?}", e);
+        }
+    });
+
+    Ok(())
+}
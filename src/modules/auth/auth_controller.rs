@@ -0,0 +1,111 @@
+use axum::{extract::State, http::StatusCode};
+use tracing::instrument;
+
+use crate::{
+    modules::{
+        auth::{
+            auth_repository::AuthRepository,
+            auth_service::AuthService,
+            presentation::{
+                dto::{
+                    ChangePasswordRequest, LoginRequest, LoginResponse, MeResponse,
+                    RefreshTokenRequest, RevokeSessionsResponse,
+                },
+                extractor::CurrentUser,
+            },
+        },
+        user::{user_repository::UserRepository, user_service::UserService},
+    },
+    presentation::{
+        error::HttpError,
+        http::{
+            common_response::SingleResponse,
+            extractor::{AppJson, AppPath, ValidatedJson},
+        },
+        state::AppState,
+    },
+};
+
+pub struct AuthController;
+
+impl AuthController {
+    fn service(state: &AppState) -> AuthService {
+        let user_repo = UserRepository::new(state.db.clone());
+        let users = UserService::new(user_repo, state.password_hasher.clone());
+        let repo = AuthRepository::new(state.db.clone());
+
+        AuthService::new(users, repo, state.jwt.clone())
+    }
+
+    #[instrument(skip(state, payload))]
+    pub async fn login_handler(
+        State(state): State<AppState>,
+        AppJson(payload): AppJson<LoginRequest>,
+    ) -> Result<SingleResponse<LoginResponse>, HttpError> {
+        let service = Self::service(&state);
+
+        let token = service.login(payload.credentials).await?;
+
+        let response = LoginResponse { token };
+
+        Ok(SingleResponse::ok(response))
+    }
+
+    #[instrument(skip(state, payload))]
+    pub async fn refresh_handler(
+        State(state): State<AppState>,
+        AppJson(payload): AppJson<RefreshTokenRequest>,
+    ) -> Result<SingleResponse<LoginResponse>, HttpError> {
+        let service = Self::service(&state);
+
+        let token = service.refresh(payload.token).await?;
+
+        let response = LoginResponse { token };
+
+        Ok(SingleResponse::ok(response))
+    }
+
+    #[instrument(skip(state, payload))]
+    pub async fn logout_handler(
+        State(state): State<AppState>,
+        AppJson(payload): AppJson<RefreshTokenRequest>,
+    ) -> Result<StatusCode, HttpError> {
+        let service = Self::service(&state);
+
+        service.logout(payload.token).await?;
+
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    #[instrument(skip(state, current, payload), fields(user_id = current.0.id))]
+    pub async fn change_password_handler(
+        State(state): State<AppState>,
+        current: CurrentUser,
+        ValidatedJson(payload): ValidatedJson<ChangePasswordRequest>,
+    ) -> Result<StatusCode, HttpError> {
+        let service = Self::service(&state);
+
+        service
+            .change_password(current.0.id, payload.password)
+            .await?;
+
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    #[instrument(skip(state))]
+    pub async fn revoke_user_sessions_handler(
+        State(state): State<AppState>,
+        AppPath(user_id): AppPath<i32>,
+    ) -> Result<SingleResponse<RevokeSessionsResponse>, HttpError> {
+        let service = Self::service(&state);
+
+        let revoked = service.revoke_user_sessions(user_id).await?;
+
+        Ok(SingleResponse::ok(RevokeSessionsResponse { revoked }))
+    }
+
+    #[instrument(skip(current), fields(user_id = current.0.id))]
+    pub async fn me_handler(current: CurrentUser) -> SingleResponse<MeResponse> {
+        SingleResponse::ok(MeResponse { user: current.0 })
+    }
+}
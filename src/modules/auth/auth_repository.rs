@@ -0,0 +1,149 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Acquire, QueryBuilder};
+use tracing::Instrument;
+use uuid::Uuid;
+
+use crate::{
+    config::db::{DbPool, acquire},
+    infrastructure::sql::trace::query_span,
+    modules::auth::{
+        domain::model::{RefreshRotation, RefreshTokenModel},
+        persistence::{
+            mutation::RefreshTokenMutation, query::RefreshTokenQuery, row::RefreshTokenRow,
+        },
+    },
+};
+
+pub struct AuthRepository {
+    db: DbPool,
+}
+
+impl AuthRepository {
+    pub fn new(db: DbPool) -> Self {
+        Self { db }
+    }
+
+    pub async fn insert_refresh_token(
+        &self,
+        user_id: i32,
+        family_id: Uuid,
+        token_hash: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        RefreshTokenMutation::insert(&mut qb, user_id, family_id, token_hash, expires_at);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        qb.build().execute(&mut *conn).instrument(span).await?;
+        Ok(())
+    }
+
+    /// Replaces the token identified by `token_hash` with `new_token_hash` in
+    /// the same family. Presenting a token that was already rotated revokes the
+    /// whole family.
+    pub async fn rotate_refresh_token(
+        &self,
+        token_hash: &str,
+        new_token_hash: &str,
+        new_expires_at: DateTime<Utc>,
+    ) -> Result<RefreshRotation, anyhow::Error> {
+        let mut conn = acquire(&self.db).await?;
+        let mut tx = conn.begin().await?;
+
+        let mut qb = QueryBuilder::new("");
+        RefreshTokenQuery::select_by_hash_for_update(&mut qb, token_hash);
+        let span = query_span(&qb);
+        let row = qb
+            .build_query_as::<RefreshTokenRow>()
+            .fetch_optional(&mut *tx)
+            .instrument(span)
+            .await?;
+
+        let Some(current) = row.map(RefreshTokenModel::from) else {
+            return Ok(RefreshRotation::NotFound);
+        };
+
+        if current.rotated_at.is_some() {
+            let mut qb = QueryBuilder::new("");
+            RefreshTokenMutation::revoke_family(&mut qb, current.family_id);
+            let span = query_span(&qb);
+            qb.build().execute(&mut *tx).instrument(span).await?;
+            tx.commit().await?;
+
+            return Ok(RefreshRotation::Reused {
+                user_id: current.user_id,
+            });
+        }
+
+        if current.revoked_at.is_some() {
+            return Ok(RefreshRotation::Revoked);
+        }
+
+        if current.expires_at <= Utc::now() {
+            return Ok(RefreshRotation::Expired);
+        }
+
+        let mut qb = QueryBuilder::new("");
+        RefreshTokenMutation::mark_rotated(&mut qb, current.id);
+        let span = query_span(&qb);
+        qb.build().execute(&mut *tx).instrument(span).await?;
+
+        let mut qb = QueryBuilder::new("");
+        RefreshTokenMutation::insert(
+            &mut qb,
+            current.user_id,
+            current.family_id,
+            new_token_hash,
+            new_expires_at,
+        );
+        let span = query_span(&qb);
+        qb.build().execute(&mut *tx).instrument(span).await?;
+
+        tx.commit().await?;
+
+        Ok(RefreshRotation::Rotated {
+            user_id: current.user_id,
+        })
+    }
+
+    /// Revokes the family the token belongs to. Returns `false` if the token is unknown.
+    pub async fn revoke_refresh_token_family(
+        &self,
+        token_hash: &str,
+    ) -> Result<bool, anyhow::Error> {
+        let mut conn = acquire(&self.db).await?;
+        let mut tx = conn.begin().await?;
+
+        let mut qb = QueryBuilder::new("");
+        RefreshTokenQuery::select_by_hash_for_update(&mut qb, token_hash);
+        let span = query_span(&qb);
+        let row = qb
+            .build_query_as::<RefreshTokenRow>()
+            .fetch_optional(&mut *tx)
+            .instrument(span)
+            .await?;
+
+        let Some(current) = row.map(RefreshTokenModel::from) else {
+            return Ok(false);
+        };
+
+        let mut qb = QueryBuilder::new("");
+        RefreshTokenMutation::revoke_family(&mut qb, current.family_id);
+        let span = query_span(&qb);
+        qb.build().execute(&mut *tx).instrument(span).await?;
+
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    /// Revokes every refresh token of the user, returning how many were active.
+    pub async fn revoke_user_refresh_tokens(&self, user_id: i32) -> Result<u64, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        RefreshTokenMutation::revoke_user(&mut qb, user_id);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let result = qb.build().execute(&mut *conn).instrument(span).await?;
+        Ok(result.rows_affected())
+    }
+}
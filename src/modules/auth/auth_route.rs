@@ -0,0 +1,33 @@
+use axum::{
+    Router,
+    handler::Handler,
+    middleware::from_fn_with_state,
+    routing::{delete, get, post, put},
+};
+
+use crate::{
+    modules::auth::{auth_controller::AuthController, domain::policy::AuthPolicy},
+    presentation::{middleware::auth::require_policy, state::AppState},
+};
+
+pub struct AuthRoute;
+
+impl AuthRoute {
+    pub fn routes() -> Router<AppState> {
+        Router::new()
+            .route("/login", post(AuthController::login_handler))
+            .route("/refresh", post(AuthController::refresh_handler))
+            .route("/logout", post(AuthController::logout_handler))
+            .route("/me", get(AuthController::me_handler))
+            .route("/password", put(AuthController::change_password_handler))
+            .route(
+                "/users/{user_id}/sessions",
+                delete(
+                    AuthController::revoke_user_sessions_handler.layer(from_fn_with_state(
+                        AuthPolicy::REVOKE_SESSIONS,
+                        require_policy,
+                    )),
+                ),
+            )
+    }
+}
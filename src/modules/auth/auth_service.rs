@@ -0,0 +1,200 @@
+use chrono::{DateTime, Utc};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    application::error::ApplicationError,
+    infrastructure::security::{
+        jwt::JwtKeys,
+        token::{generate_opaque_token, hash_opaque_token},
+    },
+    modules::{
+        auth::{
+            auth_repository::AuthRepository,
+            domain::model::{
+                ChangePasswordPayload, LoginPayload, RefreshRotation, RefreshTokenPayload,
+                TokenModel,
+            },
+            presentation::error::AuthError,
+        },
+        user::{domain::model::UserModel, user_service::UserService},
+    },
+};
+
+pub struct AuthService {
+    users: UserService,
+    repo: AuthRepository,
+    jwt: JwtKeys,
+}
+
+impl AuthService {
+    pub fn new(users: UserService, repo: AuthRepository, jwt: JwtKeys) -> Self {
+        Self { users, repo, jwt }
+    }
+
+    #[instrument(skip(self, payload), fields(login = %payload.login))]
+    pub async fn login(&self, payload: LoginPayload) -> Result<TokenModel, ApplicationError> {
+        let Some(credential) = self.users.find_credential_by_login(&payload.login).await? else {
+            // Hash anyway so response times don't reveal which logins exist.
+            self.users.verify_dummy_password(&payload.password).await?;
+            return Err(AuthError::InvalidCredentials.into());
+        };
+
+        let verified = self
+            .users
+            .verify_password(credential.user.id, &credential.password, &payload.password)
+            .await?;
+
+        if !verified {
+            return Err(AuthError::InvalidCredentials.into());
+        }
+
+        if !credential.user.status {
+            return Err(AuthError::InactiveUser.into());
+        }
+
+        // Every login starts a new refresh token family (one per session).
+        let refresh_token = generate_opaque_token();
+        let refresh_expires_at = Utc::now() + self.jwt.refresh_ttl();
+
+        self.repo
+            .insert_refresh_token(
+                credential.user.id,
+                Uuid::new_v4(),
+                &hash_opaque_token(&refresh_token),
+                refresh_expires_at,
+            )
+            .await
+            .map_err(AuthError::Unexpected)?;
+
+        self.issue_tokens(credential.user, refresh_token, refresh_expires_at)
+    }
+
+    #[instrument(skip(self, payload))]
+    pub async fn refresh(
+        &self,
+        payload: RefreshTokenPayload,
+    ) -> Result<TokenModel, ApplicationError> {
+        let refresh_token = generate_opaque_token();
+        let refresh_expires_at = Utc::now() + self.jwt.refresh_ttl();
+
+        let rotation = self
+            .repo
+            .rotate_refresh_token(
+                &hash_opaque_token(&payload.refresh_token),
+                &hash_opaque_token(&refresh_token),
+                refresh_expires_at,
+            )
+            .await
+            .map_err(AuthError::Unexpected)?;
+
+        let user_id = match rotation {
+            RefreshRotation::Rotated { user_id } => user_id,
+            RefreshRotation::Reused { user_id } => {
+                tracing::warn!(user_id, "Refresh token reuse detected, session revoked");
+                return Err(AuthError::RefreshTokenReused.into());
+            }
+            RefreshRotation::Revoked | RefreshRotation::Expired | RefreshRotation::NotFound => {
+                return Err(AuthError::InvalidRefreshToken.into());
+            }
+        };
+
+        let user = match self.users.find_user_by_id(user_id).await {
+            Ok(user) => user,
+            Err(ApplicationError::NotFound(..)) => {
+                return Err(AuthError::InvalidRefreshToken.into());
+            }
+            Err(e) => return Err(e),
+        };
+
+        if !user.status {
+            return Err(AuthError::InactiveUser.into());
+        }
+
+        self.issue_tokens(user, refresh_token, refresh_expires_at)
+    }
+
+    #[instrument(skip(self, payload))]
+    pub async fn logout(&self, payload: RefreshTokenPayload) -> Result<(), ApplicationError> {
+        let revoked = self
+            .repo
+            .revoke_refresh_token_family(&hash_opaque_token(&payload.refresh_token))
+            .await
+            .map_err(AuthError::Unexpected)?;
+
+        if !revoked {
+            return Err(AuthError::InvalidRefreshToken.into());
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the password of `user_id` once the current one is confirmed,
+    /// then revokes every session so other devices have to log in again.
+    #[instrument(skip(self, payload))]
+    pub async fn change_password(
+        &self,
+        user_id: i32,
+        payload: ChangePasswordPayload,
+    ) -> Result<(), ApplicationError> {
+        let credential = self.users.find_credential_by_id(user_id).await?;
+
+        let verified = self
+            .users
+            .verify_password(user_id, &credential.password, &payload.current_password)
+            .await?;
+
+        if !verified {
+            return Err(AuthError::IncorrectPassword.into());
+        }
+
+        self.users
+            .change_password(user_id, payload.new_password)
+            .await?;
+
+        self.repo
+            .revoke_user_refresh_tokens(user_id)
+            .await
+            .map_err(AuthError::Unexpected)?;
+
+        Ok(())
+    }
+
+    /// Revokes every session of `user_id`. Access tokens already issued stay
+    /// valid until they expire.
+    #[instrument(skip(self))]
+    pub async fn revoke_user_sessions(&self, user_id: i32) -> Result<u64, ApplicationError> {
+        // Surface a 404 for unknown users rather than silently revoking nothing.
+        self.users.find_user_by_id(user_id).await?;
+
+        let revoked = self
+            .repo
+            .revoke_user_refresh_tokens(user_id)
+            .await
+            .map_err(AuthError::Unexpected)?;
+
+        Ok(revoked)
+    }
+
+    fn issue_tokens(
+        &self,
+        user: UserModel,
+        refresh_token: String,
+        refresh_expires_at: DateTime<Utc>,
+    ) -> Result<TokenModel, ApplicationError> {
+        let role = user.role.map(|r| r.name);
+
+        let access = self
+            .jwt
+            .issue(user.id, role)
+            .map_err(AuthError::Unexpected)?;
+
+        Ok(TokenModel {
+            access_token: access.token,
+            token_type: "Bearer".to_string(),
+            expires_at: access.expires_at,
+            refresh_token,
+            refresh_expires_at,
+        })
+    }
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize)]
+pub struct LoginPayload {
+    /// Username or email.
+    #[serde(alias = "username", alias = "email")]
+    pub login: String,
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RefreshTokenPayload {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ChangePasswordPayload {
+    pub current_password: String,
+    #[validate(length(min = 8, max = 128))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TokenModel {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_at: DateTime<Utc>,
+    pub refresh_token: String,
+    pub refresh_expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+pub struct RefreshTokenModel {
+    pub id: i64,
+    pub user_id: i32,
+    /// All tokens rotated from the same login share a family.
+    pub family_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub rotated_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Outcome of presenting a refresh token for rotation.
+#[derive(Debug)]
+pub enum RefreshRotation {
+    /// The token was valid and has been replaced by a new one.
+    Rotated {
+        user_id: i32,
+    },
+    /// The token had already been rotated; its family is now revoked.
+    Reused {
+        user_id: i32,
+    },
+    Revoked,
+    Expired,
+    NotFound,
+}
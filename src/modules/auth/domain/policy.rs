@@ -0,0 +1,17 @@
+use crate::{
+    application::authorization::{Policy, PolicyRegistry},
+    modules::user::domain::policy::UserPermission,
+};
+
+pub struct AuthPolicy;
+
+impl AuthPolicy {
+    pub const REVOKE_SESSIONS: &'static str = "auth:revoke-sessions";
+
+    pub fn register(registry: &mut PolicyRegistry) {
+        registry.register(
+            Self::REVOKE_SESSIONS,
+            Policy::AllPermissions(vec![UserPermission::WRITE]),
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod auth_controller;
+pub mod auth_repository;
+pub mod auth_route;
+pub mod auth_service;
+pub mod domain;
+pub mod persistence;
+pub mod presentation;
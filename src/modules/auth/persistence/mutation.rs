@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+pub struct RefreshTokenMutation;
+
+impl RefreshTokenMutation {
+    pub const TABLE: &'static str = "refresh_tokens";
+
+    pub fn insert<'a>(
+        qb: &mut QueryBuilder<'a, Postgres>,
+        user_id: i32,
+        family_id: Uuid,
+        token_hash: &'a str,
+        expires_at: DateTime<Utc>,
+    ) {
+        qb.push(format!("INSERT INTO {}", Self::TABLE));
+        qb.push(" (user_id, family_id, token_hash, expires_at) VALUES (");
+        let mut sep = qb.separated(", ");
+        sep.push_bind(user_id);
+        sep.push_bind(family_id);
+        sep.push_bind(token_hash);
+        sep.push_bind(expires_at);
+        qb.push(")");
+    }
+
+    pub fn mark_rotated<'a>(qb: &mut QueryBuilder<'a, Postgres>, id: i64) {
+        qb.push(format!("UPDATE {} SET", Self::TABLE));
+        qb.push(" rotated_at = NOW() WHERE id = ");
+        qb.push_bind(id);
+    }
+
+    pub fn revoke_family<'a>(qb: &mut QueryBuilder<'a, Postgres>, family_id: Uuid) {
+        qb.push(format!("UPDATE {} SET", Self::TABLE));
+        qb.push(" revoked_at = NOW() WHERE family_id = ");
+        qb.push_bind(family_id);
+        qb.push(" AND revoked_at is null");
+    }
+
+    pub fn revoke_user<'a>(qb: &mut QueryBuilder<'a, Postgres>, user_id: i32) {
+        qb.push(format!("UPDATE {} SET", Self::TABLE));
+        qb.push(" revoked_at = NOW() WHERE user_id = ");
+        qb.push_bind(user_id);
+        qb.push(" AND revoked_at is null");
+    }
+}
@@ -0,0 +1,14 @@
+use sqlx::{Postgres, QueryBuilder};
+
+pub struct RefreshTokenQuery;
+
+impl RefreshTokenQuery {
+    pub const TABLE: &'static str = "refresh_tokens";
+
+    /// Locks the row so concurrent refreshes of the same token serialize.
+    pub fn select_by_hash_for_update<'a>(qb: &mut QueryBuilder<'a, Postgres>, token_hash: &'a str) {
+        qb.push(format!("SELECT * FROM {} WHERE token_hash = ", Self::TABLE));
+        qb.push_bind(token_hash);
+        qb.push(" FOR UPDATE");
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::modules::auth::domain::model::RefreshTokenModel;
+
+#[derive(Debug, FromRow)]
+pub struct RefreshTokenRow {
+    pub id: i64,
+    pub user_id: i32,
+    pub family_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    pub rotated_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<RefreshTokenRow> for RefreshTokenModel {
+    fn from(row: RefreshTokenRow) -> Self {
+        RefreshTokenModel {
+            id: row.id,
+            user_id: row.user_id,
+            family_id: row.family_id,
+            expires_at: row.expires_at,
+            rotated_at: row.rotated_at,
+            revoked_at: row.revoked_at,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{
+    auth::domain::model::{ChangePasswordPayload, LoginPayload, RefreshTokenPayload, TokenModel},
+    user::domain::model::UserModel,
+};
+
+// ===== LOGIN =====
+#[derive(Debug, Deserialize)]
+pub struct LoginRequest {
+    #[serde(flatten)]
+    pub credentials: LoginPayload,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    #[serde(flatten)]
+    pub token: TokenModel,
+}
+
+// ===== REFRESH / LOGOUT =====
+#[derive(Debug, Deserialize)]
+pub struct RefreshTokenRequest {
+    #[serde(flatten)]
+    pub token: RefreshTokenPayload,
+}
+
+// ===== PASSWORD =====
+#[derive(Debug, Deserialize)]
+pub struct ChangePasswordRequest {
+    #[serde(flatten)]
+    pub password: ChangePasswordPayload,
+}
+
+// ===== SESSIONS =====
+#[derive(Debug, Serialize)]
+pub struct RevokeSessionsResponse {
+    pub revoked: u64,
+}
+
+// ===== ME =====
+#[derive(Debug, Serialize)]
+pub struct MeResponse {
+    #[serde(flatten)]
+    pub user: UserModel,
+}
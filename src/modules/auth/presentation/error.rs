@@ -0,0 +1,58 @@
+use thiserror::Error;
+
+use crate::application::{error::ApplicationError, error_code::ErrorCode};
+
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("Invalid username/email or password")]
+    InvalidCredentials,
+
+    #[error("User account is inactive")]
+    InactiveUser,
+
+    #[error("Invalid or expired refresh token")]
+    InvalidRefreshToken,
+
+    #[error("Refresh token has already been used")]
+    RefreshTokenReused,
+
+    #[error("Current password is incorrect")]
+    IncorrectPassword,
+
+    #[error("An unexpected error occurred")]
+    Unexpected(#[from] anyhow::Error),
+}
+
+impl From<AuthError> for ApplicationError {
+    fn from(err: AuthError) -> Self {
+        match err {
+            AuthError::InvalidCredentials => ApplicationError::Unauthorized(
+                ErrorCode::AUTH_INVALID_CREDENTIALS,
+                "Invalid username/email or password".into(),
+            ),
+
+            AuthError::InactiveUser => ApplicationError::Forbidden(
+                ErrorCode::AUTH_USER_INACTIVE,
+                "User account is inactive".into(),
+            ),
+
+            AuthError::InvalidRefreshToken => ApplicationError::Unauthorized(
+                ErrorCode::AUTH_INVALID_REFRESH_TOKEN,
+                "Invalid or expired refresh token".into(),
+            ),
+
+            AuthError::RefreshTokenReused => ApplicationError::Unauthorized(
+                ErrorCode::AUTH_REFRESH_TOKEN_REUSED,
+                "Refresh token has already been used; the session has been revoked".into(),
+            ),
+
+            AuthError::IncorrectPassword => ApplicationError::InvalidField {
+                code: ErrorCode::AUTH_INCORRECT_PASSWORD,
+                field: "current_password".into(),
+                message: "Current password is incorrect".into(),
+            },
+
+            AuthError::Unexpected(e) => e.into(),
+        }
+    }
+}
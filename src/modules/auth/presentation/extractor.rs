@@ -0,0 +1,143 @@
+use axum::{
+    extract::{FromRequestParts, OptionalFromRequestParts},
+    http::{header::AUTHORIZATION, request::Parts},
+};
+
+use crate::{
+    application::{error::ApplicationError, error_code::ErrorCode},
+    modules::user::{
+        domain::model::UserModel, user_repository::UserRepository, user_service::UserService,
+    },
+    presentation::{error::HttpError, state::AppState},
+};
+
+/// The authenticated caller, resolved from an `Authorization: Bearer <token>`
+/// header. Rejects with `401` when the token is missing or invalid.
+///
+/// Use `Option<CurrentUser>` for routes where authentication is optional:
+/// a missing header yields `None`, an invalid token is still rejected.
+#[derive(Debug, Clone)]
+pub struct CurrentUser(pub UserModel);
+
+impl CurrentUser {
+    async fn resolve(parts: &mut Parts, state: &AppState, token: &str) -> Result<Self, HttpError> {
+        if let Some(current) = parts.extensions.get::<CurrentUser>() {
+            return Ok(current.clone());
+        }
+
+        let claims = state.jwt.verify(token).map_err(|_| {
+            HttpError::Unauthorized(
+                ErrorCode::AUTH_INVALID_TOKEN,
+                "Invalid or expired token".into(),
+            )
+        })?;
+
+        let repo = UserRepository::new(state.db.clone());
+        let service = UserService::new(repo, state.password_hasher.clone());
+
+        let user = match service.find_user_by_id(claims.sub).await {
+            Ok(user) => user,
+            Err(ApplicationError::NotFound(..)) => {
+                return Err(HttpError::Unauthorized(
+                    ErrorCode::AUTH_USER_NOT_FOUND,
+                    "User no longer exists".into(),
+                ));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        if !user.status {
+            return Err(HttpError::Unauthorized(
+                ErrorCode::AUTH_USER_INACTIVE,
+                "User account is inactive".into(),
+            ));
+        }
+
+        let current = CurrentUser(user);
+        parts.extensions.insert(current.clone());
+
+        Ok(current)
+    }
+}
+
+fn bearer_token(parts: &Parts) -> Result<Option<&str>, HttpError> {
+    let Some(header) = parts.headers.get(AUTHORIZATION) else {
+        return Ok(None);
+    };
+
+    header
+        .to_str()
+        .ok()
+        .and_then(parse_bearer)
+        .map(Some)
+        .ok_or_else(|| {
+            HttpError::Unauthorized(
+                ErrorCode::AUTH_MALFORMED_HEADER,
+                "Malformed Authorization header".into(),
+            )
+        })
+}
+
+/// The token of a `Bearer <token>` credential. The scheme is matched
+/// case-insensitively, as RFC 7235 requires.
+fn parse_bearer(value: &str) -> Option<&str> {
+    let (scheme, token) = value.trim_start().split_once(' ')?;
+    let token = token.trim();
+
+    (scheme.eq_ignore_ascii_case("Bearer") && !token.is_empty()).then_some(token)
+}
+
+impl FromRequestParts<AppState> for CurrentUser {
+    type Rejection = HttpError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let token = bearer_token(parts)?
+            .ok_or_else(|| {
+                HttpError::Unauthorized(
+                    ErrorCode::AUTH_MISSING_TOKEN,
+                    "Missing bearer token".into(),
+                )
+            })?
+            .to_string();
+
+        Self::resolve(parts, state, &token).await
+    }
+}
+
+impl OptionalFromRequestParts<AppState> for CurrentUser {
+    type Rejection = HttpError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Option<Self>, Self::Rejection> {
+        let Some(token) = bearer_token(parts)?.map(str::to_string) else {
+            return Ok(None);
+        };
+
+        Self::resolve(parts, state, &token).await.map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bearer_ignores_scheme_case() {
+        assert_eq!(parse_bearer("Bearer abc"), Some("abc"));
+        assert_eq!(parse_bearer("bearer abc"), Some("abc"));
+        assert_eq!(parse_bearer("BEARER  abc "), Some("abc"));
+    }
+
+    #[test]
+    fn parse_bearer_rejects_other_schemes_and_empty_tokens() {
+        assert_eq!(parse_bearer("Basic abc"), None);
+        assert_eq!(parse_bearer("Bearer"), None);
+        assert_eq!(parse_bearer("Bearer "), None);
+        assert_eq!(parse_bearer("Bearerabc"), None);
+    }
+}
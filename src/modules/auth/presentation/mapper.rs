@@ -0,0 +1,9 @@
+use validator::{Validate, ValidationErrors};
+
+use crate::modules::auth::presentation::dto::ChangePasswordRequest;
+
+impl Validate for ChangePasswordRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.password.validate()
+    }
+}
@@ -0,0 +1,4 @@
+pub mod dto;
+pub mod error;
+pub mod extractor;
+pub mod mapper;
@@ -0,0 +1,231 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use sysinfo::Disks;
+
+use crate::{
+    application::health::{CheckFuture, Criticality, HealthCheck},
+    config::{db::DbPool, migration::MIGRATOR},
+    infrastructure::system::sampler::SystemSampler,
+    modules::health::health_repository::HealthRepository,
+};
+
+/// `SELECT 1` against the pool.
+pub struct DatabaseCheck {
+    repo: HealthRepository,
+}
+
+impl DatabaseCheck {
+    pub fn new(db: DbPool) -> Self {
+        Self {
+            repo: HealthRepository::new(db),
+        }
+    }
+}
+
+impl HealthCheck for DatabaseCheck {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(2)
+    }
+
+    fn check(&self) -> CheckFuture<'_> {
+        Box::pin(self.repo.get_db_connection())
+    }
+}
+
+/// Every migration embedded in the binary has been applied, so queries
+/// match the schema they were written for.
+pub struct MigrationsCheck {
+    repo: HealthRepository,
+}
+
+impl MigrationsCheck {
+    pub fn new(db: DbPool) -> Self {
+        Self {
+            repo: HealthRepository::new(db),
+        }
+    }
+}
+
+impl HealthCheck for MigrationsCheck {
+    fn name(&self) -> &'static str {
+        "migrations"
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(2)
+    }
+
+    fn check(&self) -> CheckFuture<'_> {
+        Box::pin(async move {
+            let applied = self.repo.get_applied_migrations().await?;
+            let pending = pending_migrations(&applied);
+
+            if !pending.is_empty() {
+                anyhow::bail!("Pending migrations: {pending:?}");
+            }
+
+            Ok(())
+        })
+    }
+}
+
+fn pending_migrations(applied: &[i64]) -> Vec<i64> {
+    let applied = applied.iter().collect::<HashSet<_>>();
+
+    MIGRATOR
+        .iter()
+        .filter(|m| m.migration_type.is_up_migration() && !applied.contains(&m.version))
+        .map(|m| m.version)
+        .collect()
+}
+
+/// Free space on the disk holding `path`, e.g. the working directory that
+/// log files are written to.
+pub struct DiskSpaceCheck {
+    path: PathBuf,
+    /// Minimum available space, in percent of the disk size.
+    min_free_percent: f64,
+}
+
+impl DiskSpaceCheck {
+    pub fn new(path: PathBuf, min_free_percent: f64) -> Self {
+        Self {
+            path,
+            min_free_percent,
+        }
+    }
+}
+
+impl HealthCheck for DiskSpaceCheck {
+    fn name(&self) -> &'static str {
+        "disk_space"
+    }
+
+    fn criticality(&self) -> Criticality {
+        Criticality::Optional
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn check(&self) -> CheckFuture<'_> {
+        let path = self.path.clone();
+        let min_free_percent = self.min_free_percent;
+
+        // Listing disks and resolving the path are blocking syscalls.
+        Box::pin(async move {
+            tokio::task::spawn_blocking(move || check_disk_space(&path, min_free_percent)).await?
+        })
+    }
+}
+
+fn check_disk_space(path: &Path, min_free_percent: f64) -> anyhow::Result<()> {
+    let disks = Disks::new_with_refreshed_list();
+    let path = path.canonicalize()?;
+
+    let disk = mount_for(&path, disks.iter(), |d| d.mount_point())
+        .ok_or_else(|| anyhow::anyhow!("No disk mounted at {}", path.display()))?;
+
+    if disk.total_space() == 0 {
+        return Ok(());
+    }
+
+    let free_percent = disk.available_space() as f64 / disk.total_space() as f64 * 100.0;
+
+    if free_percent < min_free_percent {
+        anyhow::bail!(
+            "{:.1}% free on {}, below {}%",
+            free_percent,
+            disk.mount_point().display(),
+            min_free_percent
+        );
+    }
+
+    Ok(())
+}
+
+/// The item whose mount point is the longest prefix of `path`.
+fn mount_for<'a, T>(
+    path: &Path,
+    items: impl Iterator<Item = &'a T>,
+    mount_point: impl Fn(&T) -> &Path,
+) -> Option<&'a T>
+where
+    T: 'a,
+{
+    items
+        .filter(|item| path.starts_with(mount_point(item)))
+        .max_by_key(|item| mount_point(item).components().count())
+}
+
+/// The background sampler behind `/api/health` and `/metrics` is still
+/// taking samples.
+pub struct SystemSamplerCheck {
+    system: SystemSampler,
+}
+
+impl SystemSamplerCheck {
+    pub fn new(system: SystemSampler) -> Self {
+        Self { system }
+    }
+}
+
+impl HealthCheck for SystemSamplerCheck {
+    fn name(&self) -> &'static str {
+        "system_sampler"
+    }
+
+    fn criticality(&self) -> Criticality {
+        Criticality::Optional
+    }
+
+    fn check(&self) -> CheckFuture<'_> {
+        Box::pin(async move {
+            if self.system.is_stale() {
+                anyhow::bail!("No recent system sample");
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_migrations_lists_unapplied_versions() {
+        let all = pending_migrations(&[]);
+        assert!(!all.is_empty());
+
+        assert_eq!(pending_migrations(&all), Vec::<i64>::new());
+        assert_eq!(pending_migrations(&all[1..]), vec![all[0]]);
+    }
+
+    #[test]
+    fn mount_for_picks_the_most_specific_mount_point() {
+        let mounts = [
+            PathBuf::from("/"),
+            PathBuf::from("/var"),
+            PathBuf::from("/var/lib"),
+        ];
+
+        let mount = |path: &str| {
+            mount_for(Path::new(path), mounts.iter(), |m| m.as_path()).map(|m| m.as_path())
+        };
+
+        assert_eq!(mount("/var/lib/app"), Some(Path::new("/var/lib")));
+        assert_eq!(mount("/var/log"), Some(Path::new("/var")));
+        assert_eq!(mount("/home"), Some(Path::new("/")));
+    }
+}
@@ -1,18 +1,18 @@
-use axum::{extract::State, Json};
+use axum::{Json, extract::State, http::StatusCode};
 use chrono::Utc;
 use std::{
     env::consts::{ARCH, OS},
     process,
+    sync::atomic::Ordering,
 };
-use sysinfo::System;
 use tracing::instrument;
 
 use crate::{
     modules::health::{
-        health_repository::HealthRepository, health_service::HealthService,
-        presentation::dto::GetHealthResponse,
+        health_service::HealthService,
+        presentation::dto::{CheckStatus, GetHealthResponse, HealthStatus, ProbeResponse},
     },
-    presentation::{error::HttpError, state::AppState},
+    presentation::state::AppState,
 };
 
 pub struct HealthController;
@@ -21,22 +21,19 @@ impl HealthController {
     #[instrument(skip(state))]
     pub async fn healthcheck_handler(
         State(state): State<AppState>,
-    ) -> Result<Json<GetHealthResponse>, HttpError> {
-        let repo = HealthRepository::new(state.db.clone());
-        let service = HealthService::new(repo);
+    ) -> (StatusCode, Json<GetHealthResponse>) {
+        let service = HealthService::new(state.health.clone());
 
-        let mut sys = System::new_all();
+        let stats = state.system.stats();
 
-        sys.refresh_cpu_all();
-        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
-        sys.refresh_cpu_all();
-
-        sys.refresh_memory();
-
-        let db_status = service.get_db_connection().await?;
+        let checks = service.check_dependencies().await;
+        let status = HealthStatus::from_checks(&checks);
+        let db_status = checks
+            .iter()
+            .any(|c| c.name == "database" && c.status == CheckStatus::Up);
 
         let response = GetHealthResponse {
-            status: "ok".to_string(),
+            status,
             db: match db_status {
                 true => "connected",
                 false => "disconnected",
@@ -48,10 +45,64 @@ impl HealthController {
             platform: OS.to_string(),
             arch: ARCH.to_string(),
             pid: process::id().to_string(),
-            cpus: HealthService::get_cpus(&sys),
-            memory: HealthService::get_memory(&sys),
+            cpus: HealthService::get_cpus(&stats.latest),
+            memory: HealthService::get_memory(&stats.latest),
+            cpu_usage: HealthService::get_cpu_usage(&stats),
+            memory_usage: HealthService::get_memory_usage(&stats),
+            process: HealthService::get_process(&stats.latest),
+            sampled_at: stats.latest.sampled_at.map(|at| at.to_rfc3339()),
+            checks,
         };
 
-        Ok(Json(response))
+        (status.status_code(), Json(response))
+    }
+
+    /// The process is up and serving requests; dependencies are not checked,
+    /// so a database outage never gets the instance restarted.
+    #[instrument]
+    pub async fn live_handler() -> Json<ProbeResponse> {
+        Json(ProbeResponse {
+            status: HealthStatus::Ok,
+            checks: Vec::new(),
+        })
+    }
+
+    /// `503` while any required dependency is down.
+    #[instrument(skip(state))]
+    pub async fn ready_handler(State(state): State<AppState>) -> (StatusCode, Json<ProbeResponse>) {
+        let service = HealthService::new(state.health.clone());
+
+        let checks = service.check_dependencies().await;
+        let status = HealthStatus::from_checks(&checks);
+
+        (status.status_code(), Json(ProbeResponse { status, checks }))
+    }
+
+    /// `503` until every required dependency has been up once; from then on
+    /// always `200`, leaving later outages to the readiness probe.
+    #[instrument(skip(state))]
+    pub async fn startup_handler(
+        State(state): State<AppState>,
+    ) -> (StatusCode, Json<ProbeResponse>) {
+        if state.startup_complete.load(Ordering::Acquire) {
+            return (
+                StatusCode::OK,
+                Json(ProbeResponse {
+                    status: HealthStatus::Ok,
+                    checks: Vec::new(),
+                }),
+            );
+        }
+
+        let service = HealthService::new(state.health.clone());
+
+        let checks = service.check_dependencies().await;
+        let status = HealthStatus::from_checks(&checks);
+
+        if status != HealthStatus::Unavailable {
+            state.startup_complete.store(true, Ordering::Release);
+        }
+
+        (status.status_code(), Json(ProbeResponse { status, checks }))
     }
 }
@@ -1,6 +1,4 @@
-use sysinfo::System;
-
-use crate::config::db::DbPool;
+use crate::config::db::{DbPool, acquire};
 
 pub struct HealthRepository {
     db: DbPool,
@@ -11,21 +9,24 @@ impl HealthRepository {
         Self { db }
     }
 
-    pub async fn get_sys() -> System {
-        let mut sys = System::new_all();
-
-        sys.refresh_cpu_all();
-        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
-        sys.refresh_cpu_all();
+    pub async fn get_db_connection(&self) -> Result<(), anyhow::Error> {
+        let mut conn = acquire(&self.db).await?;
 
-        sys.refresh_memory();
+        sqlx::query("SELECT 1").fetch_one(&mut *conn).await?;
 
-        sys
+        Ok(())
     }
 
-    pub async fn get_db_connection(&self) -> Result<bool, anyhow::Error> {
-        let result = sqlx::query("SELECT 1").fetch_one(&self.db).await;
+    /// Versions recorded as successfully applied by the migrator.
+    pub async fn get_applied_migrations(&self) -> Result<Vec<i64>, anyhow::Error> {
+        let mut conn = acquire(&self.db).await?;
+
+        let versions = sqlx::query_scalar(
+            "SELECT version FROM _sqlx_migrations WHERE success ORDER BY version",
+        )
+        .fetch_all(&mut *conn)
+        .await?;
 
-        Ok(result.is_ok())
+        Ok(versions)
     }
 }
@@ -1,4 +1,4 @@
-use axum::{routing::get, Router};
+use axum::{Router, routing::get};
 
 use crate::{modules::health::health_controller::HealthController, presentation::state::AppState};
 
@@ -8,4 +8,12 @@ impl HealthRoute {
     pub fn routes() -> Router<AppState> {
         Router::new().route("/", get(HealthController::healthcheck_handler))
     }
+
+    /// Kubernetes-style probes, mounted at `/health` outside `/api`.
+    pub fn probe_routes() -> Router<AppState> {
+        Router::new()
+            .route("/live", get(HealthController::live_handler))
+            .route("/ready", get(HealthController::ready_handler))
+            .route("/startup", get(HealthController::startup_handler))
+    }
 }
@@ -1,52 +1,91 @@
-use sysinfo::System;
+use std::sync::Arc;
+
 use tracing::instrument;
 
 use crate::{
-    application::error::ApplicationError,
+    application::health::{Criticality, HealthRegistry},
+    infrastructure::system::sampler::{SystemSample, SystemStats},
     modules::health::{
-        health_repository::HealthRepository,
-        presentation::{dto::Cpu, error::HealthError},
+        presentation::dto::{CheckStatus, Cpu, DependencyStatus, ProcessStats, UsageAverages},
         utils::format_bytes,
     },
 };
 
 pub struct HealthService {
-    repo: HealthRepository,
+    registry: Arc<HealthRegistry>,
 }
 
 impl HealthService {
-    pub fn new(repo: HealthRepository) -> Self {
-        Self { repo }
+    pub fn new(registry: Arc<HealthRegistry>) -> Self {
+        Self { registry }
     }
 
-    #[instrument]
-    pub fn get_cpus(sys: &System) -> Vec<Cpu> {
-        sys.cpus()
+    #[instrument(skip(sample))]
+    pub fn get_cpus(sample: &SystemSample) -> Vec<Cpu> {
+        sample
+            .cpus
             .iter()
             .map(|c| Cpu {
-                model: c.brand().to_string(),
-                usage: c.cpu_usage().round(),
+                model: c.model.clone(),
+                usage: c.usage.round(),
             })
             .collect()
     }
 
-    #[instrument]
-    pub fn get_memory(sys: &System) -> String {
+    #[instrument(skip(sample))]
+    pub fn get_memory(sample: &SystemSample) -> String {
         format!(
             "{} / {}",
-            format_bytes(sys.used_memory()),
-            format_bytes(sys.total_memory())
+            format_bytes(sample.memory_used),
+            format_bytes(sample.memory_total)
         )
     }
 
+    /// Current, 1m and 5m CPU usage across all cores, in percent.
+    #[instrument(skip(stats))]
+    pub fn get_cpu_usage(stats: &SystemStats) -> UsageAverages<f32> {
+        UsageAverages {
+            current: stats.latest.cpu_usage.round(),
+            avg_1m: stats.cpu_usage.avg_1m.round() as f32,
+            avg_5m: stats.cpu_usage.avg_5m.round() as f32,
+        }
+    }
+
+    #[instrument(skip(stats))]
+    pub fn get_memory_usage(stats: &SystemStats) -> UsageAverages<String> {
+        UsageAverages {
+            current: format_bytes(stats.latest.memory_used),
+            avg_1m: format_bytes(stats.memory_used.avg_1m as u64),
+            avg_5m: format_bytes(stats.memory_used.avg_5m as u64),
+        }
+    }
+
+    #[instrument(skip(sample))]
+    pub fn get_process(sample: &SystemSample) -> ProcessStats {
+        ProcessStats {
+            cpu_usage: sample.process_cpu_usage.round(),
+            memory: format_bytes(sample.process_memory),
+        }
+    }
+
+    /// Runs every registered check; see `presentation::health` for which
+    /// ones are required.
     #[instrument(skip(self))]
-    pub async fn get_db_connection(&self) -> Result<bool, ApplicationError> {
-        let result = self
-            .repo
-            .get_db_connection()
+    pub async fn check_dependencies(&self) -> Vec<DependencyStatus> {
+        self.registry
+            .run()
             .await
-            .map_err(HealthError::Unexpected)?;
-
-        Ok(result)
+            .into_iter()
+            .map(|result| DependencyStatus {
+                name: result.name,
+                required: result.criticality == Criticality::Required,
+                status: match result.is_healthy() {
+                    true => CheckStatus::Up,
+                    false => CheckStatus::Down,
+                },
+                latency_ms: result.latency.as_millis() as u64,
+                error: result.error,
+            })
+            .collect()
     }
 }
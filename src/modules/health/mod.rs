@@ -1,3 +1,4 @@
+pub mod health_check;
 pub mod health_controller;
 pub mod health_repository;
 pub mod health_route;
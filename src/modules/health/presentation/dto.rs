@@ -1,14 +1,92 @@
+use axum::http::StatusCode;
 use serde::Serialize;
 
+/// Overall state of the service as reported by the health endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    /// An optional dependency is down; the service still serves traffic.
+    Degraded,
+    /// A required dependency is down.
+    Unavailable,
+}
+
+impl HealthStatus {
+    pub fn from_checks(checks: &[DependencyStatus]) -> Self {
+        let down = |required| {
+            checks
+                .iter()
+                .any(|c| c.required == required && c.status == CheckStatus::Down)
+        };
+
+        if down(true) {
+            Self::Unavailable
+        } else if down(false) {
+            Self::Degraded
+        } else {
+            Self::Ok
+        }
+    }
+
+    /// `503` when unavailable, so orchestrators and load balancers stop
+    /// routing to the instance; degraded still answers `200`.
+    pub fn status_code(self) -> StatusCode {
+        match self {
+            Self::Ok | Self::Degraded => StatusCode::OK,
+            Self::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DependencyStatus {
+    pub name: &'static str,
+    /// Whether the service is unavailable without this dependency.
+    pub required: bool,
+    pub status: CheckStatus,
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Body of `/health/live`, `/health/ready` and `/health/startup`.
+#[derive(Debug, Serialize)]
+pub struct ProbeResponse {
+    pub status: HealthStatus,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<DependencyStatus>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct Cpu {
     pub model: String,
     pub usage: f32,
 }
 
+#[derive(Debug, Serialize)]
+pub struct UsageAverages<T> {
+    pub current: T,
+    pub avg_1m: T,
+    pub avg_5m: T,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProcessStats {
+    pub cpu_usage: f32,
+    pub memory: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct GetHealthResponse {
-    pub status: String,
+    pub status: HealthStatus,
     pub db: String,
     pub timestamp: String,
     pub uptime: u64,
@@ -18,4 +96,44 @@ pub struct GetHealthResponse {
     pub pid: String,
     pub cpus: Vec<Cpu>,
     pub memory: String,
+    pub cpu_usage: UsageAverages<f32>,
+    pub memory_usage: UsageAverages<String>,
+    pub process: ProcessStats,
+    /// When the figures above were sampled; `None` until the first sample.
+    pub sampled_at: Option<String>,
+    pub checks: Vec<DependencyStatus>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(required: bool, status: CheckStatus) -> DependencyStatus {
+        DependencyStatus {
+            name: "dependency",
+            required,
+            status,
+            latency_ms: 0,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn status_reflects_the_most_severe_failure() {
+        use CheckStatus::{Down, Up};
+
+        assert_eq!(HealthStatus::from_checks(&[]), HealthStatus::Ok);
+        assert_eq!(
+            HealthStatus::from_checks(&[check(true, Up), check(false, Up)]),
+            HealthStatus::Ok
+        );
+        assert_eq!(
+            HealthStatus::from_checks(&[check(true, Up), check(false, Down)]),
+            HealthStatus::Degraded
+        );
+        assert_eq!(
+            HealthStatus::from_checks(&[check(true, Down), check(false, Down)]),
+            HealthStatus::Unavailable
+        );
+    }
 }
@@ -1,2 +1 @@
 pub mod dto;
-pub mod error;
@@ -0,0 +1,20 @@
+use axum::{extract::State, http::header::CONTENT_TYPE, response::IntoResponse};
+
+use crate::{modules::metrics::metrics_service::MetricsService, presentation::state::AppState};
+
+const PROMETHEUS_TEXT: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+pub struct MetricsController;
+
+impl MetricsController {
+    /// Prometheus text exposition. Pool and system gauges are refreshed on
+    /// every scrape; request metrics are recorded by `track_metrics`.
+    pub async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+        let service = MetricsService::new(state.db.clone());
+
+        service.record_db_pool();
+        service.record_system(&state.system.stats().latest);
+
+        ([(CONTENT_TYPE, PROMETHEUS_TEXT)], state.metrics.render())
+    }
+}
@@ -0,0 +1,13 @@
+use axum::{Router, routing::get};
+
+use crate::{
+    modules::metrics::metrics_controller::MetricsController, presentation::state::AppState,
+};
+
+pub struct MetricsRoute;
+
+impl MetricsRoute {
+    pub fn routes() -> Router<AppState> {
+        Router::new().route("/", get(MetricsController::metrics_handler))
+    }
+}
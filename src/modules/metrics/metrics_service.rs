@@ -0,0 +1,41 @@
+use crate::{
+    config::{
+        db::DbPool,
+        metrics::{
+            DB_POOL_CONNECTIONS, DB_POOL_MAX_CONNECTIONS, SYSTEM_CPU_USAGE_PERCENT,
+            SYSTEM_MEMORY_TOTAL_BYTES, SYSTEM_MEMORY_USED_BYTES,
+        },
+    },
+    infrastructure::system::sampler::SystemSample,
+};
+
+pub struct MetricsService {
+    db: DbPool,
+}
+
+impl MetricsService {
+    pub fn new(db: DbPool) -> Self {
+        Self { db }
+    }
+
+    /// Pool occupancy. Acquire waits are recorded as they happen, by
+    /// `config::db::acquire`.
+    pub fn record_db_pool(&self) {
+        let size = self.db.size();
+        let idle = self.db.num_idle() as u32;
+
+        metrics::gauge!(DB_POOL_CONNECTIONS, "state" => "idle").set(idle);
+        metrics::gauge!(DB_POOL_CONNECTIONS, "state" => "active").set(size.saturating_sub(idle));
+        metrics::gauge!(DB_POOL_MAX_CONNECTIONS).set(self.db.options().get_max_connections());
+    }
+
+    /// The latest figures from the background `SystemSampler`.
+    pub fn record_system(&self, sample: &SystemSample) {
+        for (index, cpu) in sample.cpus.iter().enumerate() {
+            metrics::gauge!(SYSTEM_CPU_USAGE_PERCENT, "cpu" => index.to_string()).set(cpu.usage);
+        }
+
+        metrics::gauge!(SYSTEM_MEMORY_USED_BYTES).set(sample.memory_used as f64);
+        metrics::gauge!(SYSTEM_MEMORY_TOTAL_BYTES).set(sample.memory_total as f64);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod metrics_controller;
+pub mod metrics_route;
+pub mod metrics_service;
@@ -1,3 +1,5 @@
+pub mod auth;
 pub mod health;
+pub mod metrics;
 pub mod user;
 pub mod user_role;
@@ -1,9 +1,10 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use validator::Validate;
 
 use crate::modules::user_role::domain::model::UserRoleModel;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UserModel {
     pub id: i32,
     pub username: String,
@@ -12,20 +13,42 @@ pub struct UserModel {
     pub status: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 
     pub role: Option<UserRoleModel>,
 }
 
-#[derive(Debug, Deserialize)]
+/// A user together with its stored password hash, for authentication only.
+/// Never serialize this into a response.
+#[derive(Debug)]
+pub struct UserCredential {
+    pub user: UserModel,
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
 pub struct UserPayload {
+    #[validate(length(min = 3, max = 50))]
     pub username: String,
+    #[validate(length(min = 8, max = 128))]
     pub password: String,
+    #[validate(length(min = 1, max = 100))]
     pub name: String,
+    #[validate(email, length(max = 255))]
     pub email: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct UpdateUserPayload {
+    #[validate(length(min = 3, max = 50))]
     pub username: Option<String>,
+    #[validate(length(min = 1, max = 100))]
     pub name: Option<String>,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct AssignUserRolePayload {
+    /// `None` revokes the user's current role.
+    pub role_id: Option<i32>,
+}
@@ -0,0 +1,50 @@
+use crate::{
+    application::authorization::{Policy, PolicyRegistry},
+    modules::user_role::domain::policy::UserRolePermission,
+};
+
+pub struct UserPermission;
+
+impl UserPermission {
+    pub const READ: &'static str = "users:read";
+    pub const WRITE: &'static str = "users:write";
+}
+
+pub struct UserPolicy;
+
+impl UserPolicy {
+    pub const READ: &'static str = "users:read";
+    pub const UPDATE: &'static str = "users:update";
+    pub const CREATE: &'static str = "users:create";
+    pub const DELETE: &'static str = "users:delete";
+    pub const ASSIGN_ROLE: &'static str = "users:assign-role";
+    pub const MANAGE_DELETED: &'static str = "users:manage-deleted";
+
+    pub fn register(registry: &mut PolicyRegistry) {
+        registry
+            .register(
+                Self::READ,
+                Policy::AllPermissions(vec![UserPermission::READ]),
+            )
+            .register(
+                Self::UPDATE,
+                Policy::AllPermissions(vec![UserPermission::WRITE]),
+            )
+            .register(
+                Self::CREATE,
+                Policy::AllPermissions(vec![UserPermission::WRITE]),
+            )
+            .register(
+                Self::DELETE,
+                Policy::AllPermissions(vec![UserPermission::WRITE]),
+            )
+            .register(
+                Self::MANAGE_DELETED,
+                Policy::AllPermissions(vec![UserPermission::WRITE]),
+            )
+            .register(
+                Self::ASSIGN_ROLE,
+                Policy::AllPermissions(vec![UserPermission::WRITE, UserRolePermission::WRITE]),
+            );
+    }
+}
@@ -5,7 +5,9 @@ pub enum UserJoin {
 
 #[derive(Clone)]
 pub enum UserFilter {
+    Id(i32),
     NameLike(String),
+    Username(String),
     Email(String),
     IsActive(bool),
     IsDeleted(bool),
@@ -17,4 +19,5 @@ pub enum UserOrder {
     Name,
     Email,
     CreatedAt,
+    DeletedAt,
 }
@@ -53,9 +53,51 @@ impl UserMutation {
         );
     }
 
+    pub fn update_role<'a>(qb: &mut QueryBuilder<'a, Postgres>, id: i32, role_id: Option<i32>) {
+        qb.push(format!("UPDATE {} SET", Self::TABLE));
+        qb.push(" role_id = ");
+        qb.push_bind(role_id);
+        qb.push(", updated_at = NOW() WHERE id = ");
+        qb.push_bind(id);
+        qb.push(" AND deleted_at is null");
+    }
+
+    pub fn update_password<'a>(qb: &mut QueryBuilder<'a, Postgres>, id: i32, password: &'a str) {
+        qb.push(format!("UPDATE {} SET", Self::TABLE));
+        qb.push(" password = ");
+        qb.push_bind(password);
+        qb.push(", updated_at = NOW() WHERE id = ");
+        qb.push_bind(id);
+        qb.push(" AND deleted_at is null");
+    }
+
     pub fn delete<'a>(qb: &mut QueryBuilder<'a, Postgres>, id: i32) {
         qb.push(format!("UPDATE {} SET", Self::TABLE));
         qb.push(" deleted_at = NOW() WHERE id = ");
         qb.push_bind(id);
+        qb.push(" AND deleted_at is null");
+    }
+
+    pub fn restore<'a>(qb: &mut QueryBuilder<'a, Postgres>, id: i32) {
+        qb.push(format!("UPDATE {} SET", Self::TABLE));
+        qb.push(" deleted_at = NULL, updated_at = NOW() WHERE id = ");
+        qb.push_bind(id);
+        qb.push(" AND deleted_at is not null");
+    }
+
+    /// Permanently removes a user, but only one that was soft-deleted first.
+    pub fn purge<'a>(qb: &mut QueryBuilder<'a, Postgres>, id: i32) {
+        qb.push(format!("DELETE FROM {} WHERE id = ", Self::TABLE));
+        qb.push_bind(id);
+        qb.push(" AND deleted_at is not null");
+    }
+
+    pub fn purge_deleted_before<'a>(qb: &mut QueryBuilder<'a, Postgres>, days: i32) {
+        qb.push(format!(
+            "DELETE FROM {} WHERE deleted_at < NOW() - make_interval(days => ",
+            Self::TABLE
+        ));
+        qb.push_bind(days);
+        qb.push(")");
     }
 }
@@ -53,6 +53,10 @@ impl UserQuery {
         // WHERE
         let filter_tree = Filter::And(filters.iter().cloned().map(Filter::Condition).collect());
 
+        Self::filter_tree(qb, &filter_tree);
+    }
+
+    pub fn filter_tree<'a>(qb: &mut QueryBuilder<'a, Postgres>, filter_tree: &Filter<UserFilter>) {
         filter_tree.apply(qb, Self::BASE_ALIAS, &|cond, qb, alias| {
             Self::filter_fragment(qb, cond, alias);
         });
@@ -76,12 +80,14 @@ impl UserQuery {
     fn join_fragment<'a>(qb: &mut QueryBuilder<'a, Postgres>, join: &UserJoin) {
         match join {
             UserJoin::UserRole => {
+                // A soft-deleted role is as good as no role.
                 qb.push(format!(
-                    " LEFT JOIN {} {} ON {}.id = {}.role_id ",
+                    " LEFT JOIN {} {} ON {}.id = {}.role_id AND {}.deleted_at IS NULL ",
                     UserRoleQuery::TABLE,
                     Self::ROLE_ALIAS,
                     Self::ROLE_ALIAS,
-                    Self::BASE_ALIAS
+                    Self::BASE_ALIAS,
+                    Self::ROLE_ALIAS
                 ));
             }
         }
@@ -91,12 +97,22 @@ impl UserQuery {
         qb.push(alias);
         qb.push(".");
         match cond {
+            UserFilter::Id(value) => {
+                qb.push("id = ");
+                qb.push_bind(*value);
+            }
+
             UserFilter::NameLike(value) => {
                 qb.push("name ILIKE '%' || ");
                 qb.push_bind(value.to_string());
                 qb.push(" || '%'");
             }
 
+            UserFilter::Username(value) => {
+                qb.push("username = ");
+                qb.push_bind(value.to_string());
+            }
+
             UserFilter::Email(value) => {
                 qb.push("email = ");
                 qb.push_bind(value.to_string());
@@ -125,6 +141,24 @@ impl UserQuery {
             UserOrder::Name => "name",
             UserOrder::Email => "email",
             UserOrder::CreatedAt => "created_at",
+            UserOrder::DeletedAt => "deleted_at",
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_join_skips_deleted_roles() {
+        let mut qb = QueryBuilder::new("");
+        UserQuery::select(&mut qb, &[UserJoin::UserRole]);
+
+        assert_eq!(
+            qb.sql(),
+            "SELECT u.*, to_jsonb(r.*) AS role FROM users u \
+             LEFT JOIN user_roles r ON r.id = u.role_id AND r.deleted_at IS NULL "
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use sqlx::{types::Json, FromRow};
+use sqlx::{FromRow, types::Json};
 
-use crate::modules::{user::domain::model::UserModel, user_role::persistence::row::UserRoleRow};
+use crate::modules::{
+    user::domain::model::{UserCredential, UserModel},
+    user_role::persistence::row::UserRoleRow,
+};
 
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct UserRow {
@@ -31,11 +34,23 @@ impl From<UserRow> for UserModel {
             status: row.status,
             created_at: row.created_at,
             updated_at: row.updated_at,
+            deleted_at: row.deleted_at,
             role: row.role.map(|k| k.0.into()),
         }
     }
 }
 
+impl From<UserRow> for UserCredential {
+    fn from(mut row: UserRow) -> Self {
+        let password = std::mem::take(&mut row.password);
+
+        UserCredential {
+            user: row.into(),
+            password,
+        }
+    }
+}
+
 #[derive(Debug, FromRow)]
 pub struct UserInsertRow {
     pub id: i32,
@@ -59,6 +74,7 @@ impl From<UserInsertRow> for UserModel {
             status: row.status,
             created_at: row.created_at,
             updated_at: row.updated_at,
+            deleted_at: row.deleted_at,
             role: None,
         }
     }
@@ -1,7 +1,11 @@
+use std::num::NonZeroU16;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    modules::user::domain::model::{UpdateUserPayload, UserModel, UserPayload},
+    modules::user::domain::model::{
+        AssignUserRolePayload, UpdateUserPayload, UserModel, UserPayload,
+    },
     presentation::http::common_query::ListQuery,
 };
 
@@ -32,3 +36,21 @@ pub struct UpdateUserRequest {
     #[serde(flatten)]
     pub user: UpdateUserPayload,
 }
+
+// ===== DELETED =====
+#[derive(Debug, Deserialize)]
+pub struct PurgeDeletedUsersQuery {
+    pub older_than_days: NonZeroU16,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PurgeDeletedUsersResponse {
+    pub purged: u64,
+}
+
+// ===== ROLE =====
+#[derive(Debug, Deserialize)]
+pub struct AssignUserRoleRequest {
+    #[serde(flatten)]
+    pub role: AssignUserRolePayload,
+}
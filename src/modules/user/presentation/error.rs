@@ -1,12 +1,15 @@
 use thiserror::Error;
 
-use crate::application::error::ApplicationError;
+use crate::application::{error::ApplicationError, error_code::ErrorCode};
 
 #[derive(Debug, Error)]
 pub enum UserError {
     #[error("User not found")]
     NotFound,
 
+    #[error("User role not found")]
+    RoleNotFound,
+
     #[error("An unexpected error occurred")]
     Unexpected(#[from] anyhow::Error),
 }
@@ -14,9 +17,19 @@ pub enum UserError {
 impl From<UserError> for ApplicationError {
     fn from(err: UserError) -> Self {
         match err {
-            UserError::NotFound => ApplicationError::NotFound("User not found".into()),
+            UserError::NotFound => {
+                ApplicationError::NotFound(ErrorCode::USER_NOT_FOUND, "User not found".into())
+            }
+
+            UserError::RoleNotFound => ApplicationError::UnprocessableEntity(
+                ErrorCode::USER_ROLE_NOT_FOUND,
+                "User role not found".into(),
+            ),
 
-            UserError::Unexpected(e) => ApplicationError::Unexpected(e),
+            UserError::Unexpected(e) => ApplicationError::from(e).with_conflict_codes(&[
+                ("email", ErrorCode::USER_EMAIL_TAKEN),
+                ("username", ErrorCode::USER_USERNAME_TAKEN),
+            ]),
         }
     }
 }
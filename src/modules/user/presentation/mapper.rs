@@ -1,5 +1,10 @@
+use validator::{Validate, ValidationErrors};
+
 use crate::{
-    modules::user::{domain::spec::UserFilter, presentation::dto::GetUserQuery},
+    modules::user::{
+        domain::spec::UserFilter,
+        presentation::dto::{CreateUserRequest, GetUserQuery, UpdateUserRequest},
+    },
     presentation::http::common_query::ListQueryImpl,
 };
 
@@ -23,6 +28,24 @@ impl ListQueryImpl for GetUserQuery {
     }
 }
 
+impl Validate for GetUserQuery {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.base.validate()
+    }
+}
+
+impl Validate for CreateUserRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.user.validate()
+    }
+}
+
+impl Validate for UpdateUserRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.user.validate()
+    }
+}
+
 pub fn build_user_filters(query: &GetUserQuery) -> Vec<UserFilter> {
     let mut filters = Vec::with_capacity(2);
 
@@ -1,24 +1,27 @@
-use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    Json,
-};
+use axum::{extract::State, http::StatusCode};
 use tracing::instrument;
 
 use crate::{
-    modules::user::{
-        presentation::{
-            dto::{CreateUserRequest, GetUserQuery, GetUserResponse, UpdateUserRequest},
-            mapper::build_user_filters,
+    modules::{
+        user::{
+            presentation::{
+                dto::{
+                    AssignUserRoleRequest, CreateUserRequest, GetUserQuery, GetUserResponse,
+                    PurgeDeletedUsersQuery, PurgeDeletedUsersResponse, UpdateUserRequest,
+                },
+                mapper::build_user_filters,
+            },
+            user_repository::UserRepository,
+            user_service::UserService,
         },
-        user_repository::UserRepository,
-        user_service::UserService,
+        user_role::{user_role_repository::UserRoleRepository, user_role_service::UserRoleService},
     },
     presentation::{
         error::HttpError,
         http::{
             common_query::ListQueryImpl,
             common_response::{ListResponse, SingleResponse},
+            extractor::{AppJson, AppPath, AppQuery, ValidatedJson, ValidatedQuery},
         },
         state::AppState,
     },
@@ -30,10 +33,10 @@ impl UserController {
     #[instrument(skip(state))]
     pub async fn find_all_user_handler(
         State(state): State<AppState>,
-        Query(params): Query<GetUserQuery>,
+        ValidatedQuery(params): ValidatedQuery<GetUserQuery>,
     ) -> Result<ListResponse<GetUserResponse>, HttpError> {
         let repo = UserRepository::new(state.db.clone());
-        let service = UserService::new(repo);
+        let service = UserService::new(repo, state.password_hasher.clone());
 
         let filters = build_user_filters(&params);
 
@@ -56,14 +59,29 @@ impl UserController {
         Ok(ListResponse::ok(response, total))
     }
 
+    #[instrument(skip(state))]
+    pub async fn find_user_handler(
+        State(state): State<AppState>,
+        AppPath(id): AppPath<i32>,
+    ) -> Result<SingleResponse<GetUserResponse>, HttpError> {
+        let repo = UserRepository::new(state.db.clone());
+        let service = UserService::new(repo, state.password_hasher.clone());
+
+        let found = service.find_user_by_id(id).await?;
+
+        let response = GetUserResponse { user: found };
+
+        Ok(SingleResponse::ok(response))
+    }
+
     #[instrument(skip(state))]
     pub async fn update_user_handler(
         State(state): State<AppState>,
-        Path(id): Path<i32>,
-        Json(payload): Json<UpdateUserRequest>,
+        AppPath(id): AppPath<i32>,
+        ValidatedJson(payload): ValidatedJson<UpdateUserRequest>,
     ) -> Result<SingleResponse<GetUserResponse>, HttpError> {
         let repo = UserRepository::new(state.db.clone());
-        let service = UserService::new(repo);
+        let service = UserService::new(repo, state.password_hasher.clone());
 
         let updated = service.update_user(id, payload.user).await?;
 
@@ -72,13 +90,34 @@ impl UserController {
         Ok(SingleResponse::ok(response))
     }
 
+    #[instrument(skip(state))]
+    pub async fn assign_user_role_handler(
+        State(state): State<AppState>,
+        AppPath(id): AppPath<i32>,
+        AppJson(payload): AppJson<AssignUserRoleRequest>,
+    ) -> Result<SingleResponse<GetUserResponse>, HttpError> {
+        let repo = UserRepository::new(state.db.clone());
+        let service = UserService::new(repo, state.password_hasher.clone());
+
+        let role_repo = UserRoleRepository::new(state.db.clone());
+        let roles = UserRoleService::new(role_repo, state.permissions.clone());
+
+        let updated = service
+            .assign_role(id, payload.role.role_id, &roles)
+            .await?;
+
+        let response = GetUserResponse { user: updated };
+
+        Ok(SingleResponse::ok(response))
+    }
+
     #[instrument(skip(state))]
     pub async fn delete_user_handler(
         State(state): State<AppState>,
-        Path(id): Path<i32>,
+        AppPath(id): AppPath<i32>,
     ) -> Result<StatusCode, HttpError> {
         let repo = UserRepository::new(state.db.clone());
-        let service = UserService::new(repo);
+        let service = UserService::new(repo, state.password_hasher.clone());
 
         service.delete_user(id).await?;
 
@@ -86,12 +125,82 @@ impl UserController {
     }
 
     #[instrument(skip(state))]
+    pub async fn find_all_deleted_user_handler(
+        State(state): State<AppState>,
+        ValidatedQuery(params): ValidatedQuery<GetUserQuery>,
+    ) -> Result<ListResponse<GetUserResponse>, HttpError> {
+        let repo = UserRepository::new(state.db.clone());
+        let service = UserService::new(repo, state.password_hasher.clone());
+
+        let filters = build_user_filters(&params);
+
+        let (data, total) = service
+            .find_all_deleted_user_with_count(
+                &[],
+                &filters,
+                params.sort_by(),
+                params.order(),
+                params.limit(),
+                params.start(),
+            )
+            .await?;
+
+        let response = data
+            .into_iter()
+            .map(|item| GetUserResponse { user: item })
+            .collect();
+
+        Ok(ListResponse::ok(response, total))
+    }
+
+    #[instrument(skip(state))]
+    pub async fn restore_user_handler(
+        State(state): State<AppState>,
+        AppPath(id): AppPath<i32>,
+    ) -> Result<SingleResponse<GetUserResponse>, HttpError> {
+        let repo = UserRepository::new(state.db.clone());
+        let service = UserService::new(repo, state.password_hasher.clone());
+
+        let restored = service.restore_user(id).await?;
+
+        let response = GetUserResponse { user: restored };
+
+        Ok(SingleResponse::ok(response))
+    }
+
+    #[instrument(skip(state))]
+    pub async fn purge_user_handler(
+        State(state): State<AppState>,
+        AppPath(id): AppPath<i32>,
+    ) -> Result<StatusCode, HttpError> {
+        let repo = UserRepository::new(state.db.clone());
+        let service = UserService::new(repo, state.password_hasher.clone());
+
+        service.purge_user(id).await?;
+
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    #[instrument(skip(state))]
+    pub async fn purge_deleted_users_handler(
+        State(state): State<AppState>,
+        AppQuery(params): AppQuery<PurgeDeletedUsersQuery>,
+    ) -> Result<SingleResponse<PurgeDeletedUsersResponse>, HttpError> {
+        let repo = UserRepository::new(state.db.clone());
+        let service = UserService::new(repo, state.password_hasher.clone());
+
+        let purged = service.purge_deleted_users(params.older_than_days).await?;
+
+        Ok(SingleResponse::ok(PurgeDeletedUsersResponse { purged }))
+    }
+
+    #[instrument(skip(state, payload))]
     pub async fn create_user_handler(
         State(state): State<AppState>,
-        Json(payload): Json<CreateUserRequest>,
+        ValidatedJson(payload): ValidatedJson<CreateUserRequest>,
     ) -> Result<SingleResponse<GetUserResponse>, HttpError> {
         let repo = UserRepository::new(state.db.clone());
-        let service = UserService::new(repo);
+        let service = UserService::new(repo, state.password_hasher.clone());
 
         let saved = service.create_user(payload.user).await?;
 
@@ -1,14 +1,18 @@
-use sqlx::QueryBuilder;
+use sqlx::{Acquire, QueryBuilder};
+use tracing::Instrument;
 
 use crate::{
-    config::db::DbPool,
+    config::db::{DbPool, acquire},
     infrastructure::sql::{
+        error::DbError,
+        filter::Filter,
         order::{Order, OrderBy},
         pagination::Pagination,
+        trace::query_span,
     },
     modules::user::{
         domain::{
-            model::{UpdateUserPayload, UserModel, UserPayload},
+            model::{UpdateUserPayload, UserCredential, UserModel, UserPayload},
             spec::{UserFilter, UserJoin, UserOrder},
         },
         persistence::{
@@ -51,11 +55,38 @@ impl UserRepository {
         let pagination = Pagination::new(limit, offset);
         pagination.apply(&mut qb);
 
-        let rows = qb.build_query_as::<UserRow>().fetch_all(&self.db).await?;
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let rows = qb
+            .build_query_as::<UserRow>()
+            .fetch_all(&mut *conn)
+            .instrument(span)
+            .await?;
 
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
+    pub async fn find_one(
+        &self,
+        joins: &[UserJoin],
+        filters: &[UserFilter],
+    ) -> Result<Option<UserModel>, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+
+        UserQuery::select(&mut qb, joins);
+        UserQuery::filter(&mut qb, filters);
+
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let row = qb
+            .build_query_as::<UserRow>()
+            .fetch_optional(&mut *conn)
+            .instrument(span)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
     pub async fn count_all(
         &self,
         joins: &[UserJoin],
@@ -66,21 +97,87 @@ impl UserRepository {
         UserQuery::count(&mut qb, joins);
         UserQuery::filter(&mut qb, filters);
 
-        let count: i64 = qb.build_query_scalar().fetch_one(&self.db).await?;
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let count: i64 = qb
+            .build_query_scalar()
+            .fetch_one(&mut *conn)
+            .instrument(span)
+            .await?;
 
         Ok(count)
     }
 
+    pub async fn find_credential(
+        &self,
+        joins: &[UserJoin],
+        filter: &Filter<UserFilter>,
+    ) -> Result<Option<UserCredential>, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+
+        UserQuery::select(&mut qb, joins);
+        UserQuery::filter_tree(&mut qb, filter);
+
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let row = qb
+            .build_query_as::<UserRow>()
+            .fetch_optional(&mut *conn)
+            .instrument(span)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
     pub async fn insert(&self, payload: UserPayload) -> Result<UserModel, anyhow::Error> {
         let mut qb = QueryBuilder::new("");
         UserMutation::insert(&mut qb, &payload);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
         let row = qb
             .build_query_as::<UserInsertRow>()
-            .fetch_one(&self.db)
-            .await?;
+            .fetch_one(&mut *conn)
+            .instrument(span)
+            .await
+            .map_err(DbError::from)?;
         Ok(row.into())
     }
 
+    /// Inserts the user and assigns `role_id` in one transaction, so a failed
+    /// assignment never leaves a user without its role behind.
+    pub async fn insert_with_role(
+        &self,
+        payload: UserPayload,
+        role_id: i32,
+    ) -> Result<UserModel, anyhow::Error> {
+        let mut conn = acquire(&self.db).await?;
+        let mut tx = conn.begin().await?;
+
+        let mut qb = QueryBuilder::new("");
+        UserMutation::insert(&mut qb, &payload);
+        let span = query_span(&qb);
+        let row = qb
+            .build_query_as::<UserInsertRow>()
+            .fetch_one(&mut *tx)
+            .instrument(span)
+            .await
+            .map_err(DbError::from)?;
+        let user = UserModel::from(row);
+
+        let mut qb = QueryBuilder::new("");
+        UserMutation::update_role(&mut qb, user.id, Some(role_id));
+        let span = query_span(&qb);
+        qb.build()
+            .execute(&mut *tx)
+            .instrument(span)
+            .await
+            .map_err(DbError::from)?;
+
+        tx.commit().await?;
+
+        Ok(user)
+    }
+
     pub async fn update(
         &self,
         id: i32,
@@ -88,16 +185,73 @@ impl UserRepository {
     ) -> Result<Option<UserModel>, anyhow::Error> {
         let mut qb = QueryBuilder::new("");
         UserMutation::update(&mut qb, id, &payload);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
         let row = qb
             .build_query_as::<UserInsertRow>()
-            .fetch_optional(&self.db)
-            .await?;
+            .fetch_optional(&mut *conn)
+            .instrument(span)
+            .await
+            .map_err(DbError::from)?;
         Ok(row.map(Into::into))
     }
 
-    pub async fn delete(&self, id: i32) -> Result<(), anyhow::Error> {
+    pub async fn update_role(&self, id: i32, role_id: Option<i32>) -> Result<bool, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserMutation::update_role(&mut qb, id, role_id);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let result = qb
+            .build()
+            .execute(&mut *conn)
+            .instrument(span)
+            .await
+            .map_err(DbError::from)?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn update_password(&self, id: i32, password: &str) -> Result<bool, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserMutation::update_password(&mut qb, id, password);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let result = qb.build().execute(&mut *conn).instrument(span).await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn delete(&self, id: i32) -> Result<u64, anyhow::Error> {
         let mut qb = QueryBuilder::new("");
         UserMutation::delete(&mut qb, id);
-        Ok(())
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let result = qb.build().execute(&mut *conn).instrument(span).await?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn restore(&self, id: i32) -> Result<u64, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserMutation::restore(&mut qb, id);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let result = qb.build().execute(&mut *conn).instrument(span).await?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn purge(&self, id: i32) -> Result<u64, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserMutation::purge(&mut qb, id);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let result = qb.build().execute(&mut *conn).instrument(span).await?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn purge_deleted_before(&self, days: i32) -> Result<u64, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserMutation::purge_deleted_before(&mut qb, days);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let result = qb.build().execute(&mut *conn).instrument(span).await?;
+        Ok(result.rows_affected())
     }
 }
@@ -1,9 +1,14 @@
 use axum::{
-    routing::{get, put},
     Router,
+    handler::Handler,
+    middleware::from_fn_with_state,
+    routing::{delete, get, post, put},
 };
 
-use crate::{modules::user::user_controller::UserController, presentation::state::AppState};
+use crate::{
+    modules::user::{domain::policy::UserPolicy, user_controller::UserController},
+    presentation::{middleware::auth::require_policy, state::AppState},
+};
 
 pub struct UserRoute;
 
@@ -12,13 +17,58 @@ impl UserRoute {
         Router::new()
             .route(
                 "/",
-                get(UserController::find_all_user_handler)
-                    .post(UserController::create_user_handler),
+                get(UserController::find_all_user_handler
+                    .layer(from_fn_with_state(UserPolicy::READ, require_policy)))
+                .post(
+                    UserController::create_user_handler
+                        .layer(from_fn_with_state(UserPolicy::CREATE, require_policy)),
+                ),
             )
             .route(
                 "/{id}",
-                put(UserController::update_user_handler)
-                    .delete(UserController::delete_user_handler),
+                get(UserController::find_user_handler
+                    .layer(from_fn_with_state(UserPolicy::READ, require_policy)))
+                .put(
+                    UserController::update_user_handler
+                        .layer(from_fn_with_state(UserPolicy::UPDATE, require_policy)),
+                )
+                .delete(
+                    UserController::delete_user_handler
+                        .layer(from_fn_with_state(UserPolicy::DELETE, require_policy)),
+                ),
+            )
+            .route(
+                "/deleted",
+                get(
+                    UserController::find_all_deleted_user_handler.layer(from_fn_with_state(
+                        UserPolicy::MANAGE_DELETED,
+                        require_policy,
+                    )),
+                )
+                .delete(UserController::purge_deleted_users_handler.layer(
+                    from_fn_with_state(UserPolicy::MANAGE_DELETED, require_policy),
+                )),
+            )
+            .route(
+                "/{id}/restore",
+                post(
+                    UserController::restore_user_handler.layer(from_fn_with_state(
+                        UserPolicy::MANAGE_DELETED,
+                        require_policy,
+                    )),
+                ),
+            )
+            .route(
+                "/{id}/purge",
+                delete(UserController::purge_user_handler.layer(from_fn_with_state(
+                    UserPolicy::MANAGE_DELETED,
+                    require_policy,
+                ))),
+            )
+            .route(
+                "/{id}/role",
+                put(UserController::assign_user_role_handler
+                    .layer(from_fn_with_state(UserPolicy::ASSIGN_ROLE, require_policy))),
             )
     }
 }
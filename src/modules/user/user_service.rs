@@ -1,24 +1,34 @@
+use std::num::NonZeroU16;
+
 use tracing::instrument;
 
 use crate::{
     application::error::ApplicationError,
-    modules::user::{
-        domain::{
-            model::{UpdateUserPayload, UserModel, UserPayload},
-            spec::{UserFilter, UserJoin, UserOrder},
+    infrastructure::{
+        security::password::{PasswordHasher, PasswordVerification},
+        sql::filter::Filter,
+    },
+    modules::{
+        user::{
+            domain::{
+                model::{UpdateUserPayload, UserCredential, UserModel, UserPayload},
+                spec::{UserFilter, UserJoin, UserOrder},
+            },
+            presentation::error::UserError,
+            user_repository::UserRepository,
         },
-        presentation::error::UserError,
-        user_repository::UserRepository,
+        user_role::user_role_service::UserRoleService,
     },
 };
 
 pub struct UserService {
     repo: UserRepository,
+    hasher: PasswordHasher,
 }
 
 impl UserService {
-    pub fn new(repo: UserRepository) -> Self {
-        Self { repo }
+    pub fn new(repo: UserRepository, hasher: PasswordHasher) -> Self {
+        Self { repo, hasher }
     }
 
     #[instrument(skip(self, joins, filters))]
@@ -30,6 +40,36 @@ impl UserService {
         order: Option<&str>,
         limit: Option<i32>,
         offset: Option<i32>,
+    ) -> Result<(Vec<UserModel>, i64), ApplicationError> {
+        self.find_with_count(false, joins, filters, sort_by, order, limit, offset)
+            .await
+    }
+
+    /// Same as `find_all_user_with_count`, but over soft-deleted users only.
+    #[instrument(skip(self, joins, filters))]
+    pub async fn find_all_deleted_user_with_count(
+        &self,
+        joins: &[UserJoin],
+        filters: &[UserFilter],
+        sort_by: Option<&str>,
+        order: Option<&str>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<(Vec<UserModel>, i64), ApplicationError> {
+        self.find_with_count(true, joins, filters, sort_by, order, limit, offset)
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn find_with_count(
+        &self,
+        deleted: bool,
+        joins: &[UserJoin],
+        filters: &[UserFilter],
+        sort_by: Option<&str>,
+        order: Option<&str>,
+        limit: Option<i32>,
+        offset: Option<i32>,
     ) -> Result<(Vec<UserModel>, i64), ApplicationError> {
         // ===== JOIN =====
         let mut effective_joins = Vec::with_capacity(1 + joins.len());
@@ -38,12 +78,13 @@ impl UserService {
 
         // ===== FILTER =====
         let mut effective_filters = Vec::with_capacity(1 + filters.len());
-        effective_filters.push(UserFilter::IsDeleted(false));
+        effective_filters.push(UserFilter::IsDeleted(deleted));
         effective_filters.extend_from_slice(filters);
 
         // ===== ORDER =====
         let order_field = match sort_by {
             Some("name") => UserOrder::Name,
+            Some("deleted_at") if deleted => UserOrder::DeletedAt,
             _ => UserOrder::Id,
         };
 
@@ -66,6 +107,62 @@ impl UserService {
         Ok((data, total))
     }
 
+    #[instrument(skip(self))]
+    pub async fn find_user_by_id(&self, id: i32) -> Result<UserModel, ApplicationError> {
+        let filters = [UserFilter::IsDeleted(false), UserFilter::Id(id)];
+
+        let data = self
+            .repo
+            .find_one(&[UserJoin::UserRole], &filters)
+            .await
+            .map_err(UserError::Unexpected)?
+            .ok_or(UserError::NotFound)?;
+
+        Ok(data)
+    }
+
+    /// Looks up a non-deleted user by username or email, including the
+    /// password hash and role needed to authenticate them.
+    #[instrument(skip(self))]
+    pub async fn find_credential_by_login(
+        &self,
+        login: &str,
+    ) -> Result<Option<UserCredential>, ApplicationError> {
+        let filter = Filter::And(vec![
+            Filter::Condition(UserFilter::IsDeleted(false)),
+            Filter::Or(vec![
+                Filter::Condition(UserFilter::Username(login.to_string())),
+                Filter::Condition(UserFilter::Email(login.to_string())),
+            ]),
+        ]);
+
+        let data = self
+            .repo
+            .find_credential(&[UserJoin::UserRole], &filter)
+            .await
+            .map_err(UserError::Unexpected)?;
+
+        Ok(data)
+    }
+
+    /// Looks up a non-deleted user by id, including the password hash.
+    #[instrument(skip(self))]
+    pub async fn find_credential_by_id(&self, id: i32) -> Result<UserCredential, ApplicationError> {
+        let filter = Filter::And(vec![
+            Filter::Condition(UserFilter::IsDeleted(false)),
+            Filter::Condition(UserFilter::Id(id)),
+        ]);
+
+        let data = self
+            .repo
+            .find_credential(&[UserJoin::UserRole], &filter)
+            .await
+            .map_err(UserError::Unexpected)?
+            .ok_or(UserError::NotFound)?;
+
+        Ok(data)
+    }
+
     #[instrument(skip(self))]
     pub async fn update_user(
         &self,
@@ -82,8 +179,45 @@ impl UserService {
         Ok(data)
     }
 
-    #[instrument(skip(self))]
-    pub async fn create_user(&self, payload: UserPayload) -> Result<UserModel, ApplicationError> {
+    /// Sets or, with `role_id: None`, revokes the user's role. A role that does
+    /// not exist or is soft-deleted is reported as `UserError::RoleNotFound`.
+    #[instrument(skip(self, roles))]
+    pub async fn assign_role(
+        &self,
+        id: i32,
+        role_id: Option<i32>,
+        roles: &UserRoleService,
+    ) -> Result<UserModel, ApplicationError> {
+        if let Some(role_id) = role_id {
+            roles
+                .find_user_role_by_id(role_id)
+                .await
+                .map_err(|e| match e {
+                    ApplicationError::NotFound(..) => UserError::RoleNotFound.into(),
+                    e => e,
+                })?;
+        }
+
+        let updated = self
+            .repo
+            .update_role(id, role_id)
+            .await
+            .map_err(UserError::Unexpected)?;
+
+        if !updated {
+            return Err(UserError::NotFound.into());
+        }
+
+        self.find_user_by_id(id).await
+    }
+
+    #[instrument(skip(self, payload), fields(username = %payload.username))]
+    pub async fn create_user(
+        &self,
+        mut payload: UserPayload,
+    ) -> Result<UserModel, ApplicationError> {
+        payload.password = self.hash_password(payload.password).await?;
+
         let data = self
             .repo
             .insert(payload)
@@ -93,10 +227,149 @@ impl UserService {
         Ok(data)
     }
 
+    /// Creates a user that already holds `role_id`, e.g. the first admin.
+    #[instrument(skip(self, payload), fields(username = %payload.username))]
+    pub async fn create_user_with_role(
+        &self,
+        mut payload: UserPayload,
+        role_id: i32,
+    ) -> Result<UserModel, ApplicationError> {
+        payload.password = self.hash_password(payload.password).await?;
+
+        let user = self
+            .repo
+            .insert_with_role(payload, role_id)
+            .await
+            .map_err(UserError::Unexpected)?;
+
+        self.find_user_by_id(user.id).await
+    }
+
     #[instrument(skip(self))]
     pub async fn delete_user(&self, id: i32) -> Result<(), ApplicationError> {
-        self.repo.delete(id).await.map_err(UserError::Unexpected)?;
+        let affected = self.repo.delete(id).await.map_err(UserError::Unexpected)?;
+
+        if affected == 0 {
+            return Err(UserError::NotFound.into());
+        }
 
         Ok(())
     }
+
+    /// Undoes a soft delete. Users that are not deleted report `NotFound`.
+    #[instrument(skip(self))]
+    pub async fn restore_user(&self, id: i32) -> Result<UserModel, ApplicationError> {
+        let affected = self.repo.restore(id).await.map_err(UserError::Unexpected)?;
+
+        if affected == 0 {
+            return Err(UserError::NotFound.into());
+        }
+
+        self.find_user_by_id(id).await
+    }
+
+    /// Permanently removes a soft-deleted user. Active users must be deleted
+    /// first, so a purge can never be the first step of removing an account.
+    #[instrument(skip(self))]
+    pub async fn purge_user(&self, id: i32) -> Result<(), ApplicationError> {
+        let affected = self.repo.purge(id).await.map_err(UserError::Unexpected)?;
+
+        if affected == 0 {
+            return Err(UserError::NotFound.into());
+        }
+
+        Ok(())
+    }
+
+    /// Permanently removes every user soft-deleted more than `days` days ago,
+    /// returning how many were purged. `days` is never zero, which would
+    /// purge every deleted user at once.
+    #[instrument(skip(self))]
+    pub async fn purge_deleted_users(&self, days: NonZeroU16) -> Result<u64, ApplicationError> {
+        let purged = self
+            .repo
+            .purge_deleted_before(i32::from(days.get()))
+            .await
+            .map_err(UserError::Unexpected)?;
+
+        Ok(purged)
+    }
+
+    #[instrument(skip(self, password))]
+    pub async fn change_password(&self, id: i32, password: String) -> Result<(), ApplicationError> {
+        let hash = self.hash_password(password).await?;
+
+        let updated = self
+            .repo
+            .update_password(id, &hash)
+            .await
+            .map_err(UserError::Unexpected)?;
+
+        if !updated {
+            return Err(UserError::NotFound.into());
+        }
+
+        Ok(())
+    }
+
+    /// Verifies `password` against a dummy hash, so a login for an unknown
+    /// user takes as long as one with a wrong password.
+    #[instrument(skip(self, password))]
+    pub async fn verify_dummy_password(&self, password: &str) -> Result<(), ApplicationError> {
+        let hasher = self.hasher.clone();
+        let candidate = password.to_string();
+
+        tokio::task::spawn_blocking(move || hasher.verify_dummy(&candidate))
+            .await
+            .map_err(|e| UserError::Unexpected(e.into()))?
+            .map_err(UserError::Unexpected)?;
+
+        Ok(())
+    }
+
+    /// Checks `password` against the user's stored PHC hash. When the hash was
+    /// produced with outdated argon2 parameters it is transparently replaced.
+    #[instrument(skip(self, stored_hash, password))]
+    pub async fn verify_password(
+        &self,
+        id: i32,
+        stored_hash: &str,
+        password: &str,
+    ) -> Result<bool, ApplicationError> {
+        let hasher = self.hasher.clone();
+        let stored_hash = stored_hash.to_string();
+        let candidate = password.to_string();
+
+        let verification =
+            tokio::task::spawn_blocking(move || hasher.verify(&candidate, &stored_hash))
+                .await
+                .map_err(|e| UserError::Unexpected(e.into()))?
+                .map_err(UserError::Unexpected)?;
+
+        match verification {
+            PasswordVerification::Invalid => Ok(false),
+            PasswordVerification::Valid => Ok(true),
+            PasswordVerification::ValidNeedsRehash => {
+                let hash = self.hash_password(password.to_string()).await?;
+
+                // A failed rehash must not fail the login itself.
+                if let Err(e) = self.repo.update_password(id, &hash).await {
+                    tracing::warn!(user_id = id, error = ?e, "Failed to rehash password");
+                }
+
+                Ok(true)
+            }
+        }
+    }
+
+    async fn hash_password(&self, password: String) -> Result<String, ApplicationError> {
+        let hasher = self.hasher.clone();
+
+        let hash = tokio::task::spawn_blocking(move || hasher.hash(&password))
+            .await
+            .map_err(|e| UserError::Unexpected(e.into()))?
+            .map_err(UserError::Unexpected)?;
+
+        Ok(hash)
+    }
 }
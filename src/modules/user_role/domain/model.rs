@@ -1,10 +1,33 @@
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UserRoleModel {
     pub id: i32,
     pub name: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+impl UserRoleModel {
+    pub const ADMIN: &'static str = "admin";
+    pub const MEMBER: &'static str = "member";
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UserRolePayload {
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateUserRolePayload {
+    #[validate(length(min = 1, max = 50))]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserRolePermissionsPayload {
+    pub permissions: Vec<String>,
+}
@@ -0,0 +1,27 @@
+use crate::application::authorization::{Policy, PolicyRegistry};
+
+pub struct UserRolePermission;
+
+impl UserRolePermission {
+    pub const READ: &'static str = "user_roles:read";
+    pub const WRITE: &'static str = "user_roles:write";
+}
+
+pub struct UserRolePolicy;
+
+impl UserRolePolicy {
+    pub const READ: &'static str = "user_roles:read";
+    pub const WRITE: &'static str = "user_roles:write";
+
+    pub fn register(registry: &mut PolicyRegistry) {
+        registry
+            .register(
+                Self::READ,
+                Policy::AllPermissions(vec![UserRolePermission::READ]),
+            )
+            .register(
+                Self::WRITE,
+                Policy::AllPermissions(vec![UserRolePermission::WRITE]),
+            );
+    }
+}
@@ -0,0 +1,14 @@
+#[derive(Clone)]
+pub enum UserRoleFilter {
+    Id(i32),
+    Name(String),
+    NameLike(String),
+    IsDeleted(bool),
+}
+
+#[derive(Clone)]
+pub enum UserRoleOrder {
+    Id,
+    Name,
+    CreatedAt,
+}
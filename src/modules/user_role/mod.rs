@@ -1,3 +1,7 @@
 pub mod domain;
 pub mod persistence;
 pub mod presentation;
+pub mod user_role_controller;
+pub mod user_role_repository;
+pub mod user_role_route;
+pub mod user_role_service;
@@ -0,0 +1,76 @@
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::modules::user_role::{
+    domain::model::{UpdateUserRolePayload, UserRolePayload},
+    persistence::query::UserRoleQuery,
+};
+
+pub struct UserRoleMutation;
+
+impl UserRoleMutation {
+    pub const TABLE: &'static str = "user_roles";
+
+    pub fn insert<'a>(qb: &mut QueryBuilder<'a, Postgres>, payload: &'a UserRolePayload) {
+        qb.push(format!("INSERT INTO {}", Self::TABLE));
+        qb.push(" (name) VALUES (");
+        qb.push_bind(&payload.name);
+        qb.push(") RETURNING id, name, created_at, updated_at, deleted_at");
+    }
+
+    pub fn update<'a>(
+        qb: &mut QueryBuilder<'a, Postgres>,
+        id: i32,
+        payload: &'a UpdateUserRolePayload,
+    ) {
+        qb.push(format!("UPDATE {} SET ", Self::TABLE));
+
+        let mut sep = qb.separated(", ");
+
+        if let Some(v) = &payload.name {
+            sep.push("name = ");
+            sep.push_bind_unseparated(v.as_str());
+        }
+
+        sep.push("updated_at = NOW()");
+
+        qb.push(" WHERE id = ");
+        qb.push_bind(id);
+        qb.push(
+            " AND deleted_at is null \
+            RETURNING id, name, created_at, updated_at, deleted_at",
+        );
+    }
+
+    pub fn delete<'a>(qb: &mut QueryBuilder<'a, Postgres>, id: i32) {
+        qb.push(format!("UPDATE {} SET", Self::TABLE));
+        qb.push(" deleted_at = NOW() WHERE id = ");
+        qb.push_bind(id);
+        qb.push(" AND deleted_at is null");
+    }
+
+    pub fn clear_permissions<'a>(qb: &mut QueryBuilder<'a, Postgres>, role_id: i32) {
+        qb.push(format!(
+            "DELETE FROM {} WHERE role_id = ",
+            UserRoleQuery::ROLE_PERMISSION_TABLE
+        ));
+        qb.push_bind(role_id);
+    }
+
+    pub fn assign_permissions<'a>(
+        qb: &mut QueryBuilder<'a, Postgres>,
+        role_id: i32,
+        permissions: &'a [String],
+    ) {
+        qb.push(format!(
+            "INSERT INTO {} (role_id, permission_id) SELECT ",
+            UserRoleQuery::ROLE_PERMISSION_TABLE
+        ));
+        qb.push_bind(role_id);
+        qb.push(format!(
+            ", id FROM {} WHERE name = ANY(",
+            UserRoleQuery::PERMISSION_TABLE
+        ));
+        qb.push_bind(permissions);
+        qb.push(") ON CONFLICT DO NOTHING");
+    }
+}
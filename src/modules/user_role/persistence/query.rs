@@ -1,5 +1,144 @@
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::{
+    infrastructure::sql::{filter::Filter, order::OrderBy},
+    modules::user_role::domain::spec::{UserRoleFilter, UserRoleOrder},
+};
+
 pub struct UserRoleQuery;
 
 impl UserRoleQuery {
     pub const TABLE: &'static str = "user_roles";
+    pub const PERMISSION_TABLE: &'static str = "permissions";
+    pub const ROLE_PERMISSION_TABLE: &'static str = "role_permissions";
+    const BASE_ALIAS: &'static str = "r";
+
+    pub fn select<'a>(qb: &mut QueryBuilder<'a, Postgres>) {
+        // SELECT
+        qb.push(format!("SELECT {}.*", Self::BASE_ALIAS));
+
+        // FROM
+        qb.push(format!(" FROM {} {}", Self::TABLE, Self::BASE_ALIAS));
+    }
+
+    pub fn count<'a>(qb: &mut QueryBuilder<'a, Postgres>) {
+        // SELECT
+        qb.push("SELECT COUNT(*)");
+
+        // FROM
+        qb.push(format!(" FROM {} {}", Self::TABLE, Self::BASE_ALIAS));
+    }
+
+    pub fn filter<'a>(qb: &mut QueryBuilder<'a, Postgres>, filters: &[UserRoleFilter]) {
+        if filters.is_empty() {
+            return;
+        }
+
+        // WHERE
+        let filter_tree = Filter::And(filters.iter().cloned().map(Filter::Condition).collect());
+
+        filter_tree.apply(qb, Self::BASE_ALIAS, &|cond, qb, alias| {
+            Self::filter_fragment(qb, cond, alias);
+        });
+    }
+
+    pub fn order<'a>(qb: &mut QueryBuilder<'a, Postgres>, orders: &OrderBy<UserRoleOrder>) {
+        // ORDER
+        orders.apply(qb, Self::BASE_ALIAS, &|col, qb, alias| {
+            Self::order_fragment(qb, col, alias);
+        });
+    }
+
+    /// Permissions granted by `role_id`; none once the role is soft-deleted.
+    pub fn select_permission_names<'a>(qb: &mut QueryBuilder<'a, Postgres>, role_id: i32) {
+        qb.push(format!(
+            "SELECT p.name FROM {} rp JOIN {} p ON p.id = rp.permission_id \
+             JOIN {} {} ON {}.id = rp.role_id AND {}.deleted_at IS NULL",
+            Self::ROLE_PERMISSION_TABLE,
+            Self::PERMISSION_TABLE,
+            Self::TABLE,
+            Self::BASE_ALIAS,
+            Self::BASE_ALIAS,
+            Self::BASE_ALIAS
+        ));
+        qb.push(" WHERE rp.role_id = ");
+        qb.push_bind(role_id);
+        qb.push(" ORDER BY p.name");
+    }
+
+    /// Names from `names` that match no row in the permissions table.
+    pub fn select_unknown_permission_names<'a>(
+        qb: &mut QueryBuilder<'a, Postgres>,
+        names: &'a [String],
+    ) {
+        qb.push("SELECT n.name FROM UNNEST(");
+        qb.push_bind(names);
+        qb.push(format!(
+            "::text[]) AS n(name) WHERE NOT EXISTS (SELECT 1 FROM {} p WHERE p.name = n.name)",
+            Self::PERMISSION_TABLE
+        ));
+        qb.push(" ORDER BY n.name");
+    }
+
+    fn filter_fragment<'a>(
+        qb: &mut QueryBuilder<'a, Postgres>,
+        cond: &UserRoleFilter,
+        alias: &str,
+    ) {
+        qb.push(alias);
+        qb.push(".");
+        match cond {
+            UserRoleFilter::Id(value) => {
+                qb.push("id = ");
+                qb.push_bind(*value);
+            }
+
+            UserRoleFilter::Name(value) => {
+                qb.push("name = ");
+                qb.push_bind(value.to_string());
+            }
+
+            UserRoleFilter::NameLike(value) => {
+                qb.push("name ILIKE '%' || ");
+                qb.push_bind(value.to_string());
+                qb.push(" || '%'");
+            }
+
+            UserRoleFilter::IsDeleted(deleted) => {
+                if *deleted {
+                    qb.push("deleted_at IS NOT NULL");
+                } else {
+                    qb.push("deleted_at IS NULL");
+                }
+            }
+        }
+    }
+
+    fn order_fragment<'a>(qb: &mut QueryBuilder<'a, Postgres>, col: &UserRoleOrder, alias: &str) {
+        qb.push(alias);
+        qb.push(".");
+        qb.push(match col {
+            UserRoleOrder::Id => "id",
+            UserRoleOrder::Name => "name",
+            UserRoleOrder::CreatedAt => "created_at",
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deleted_roles_grant_no_permissions() {
+        let mut qb = QueryBuilder::new("");
+        UserRoleQuery::select_permission_names(&mut qb, 1);
+
+        assert_eq!(
+            qb.sql(),
+            "SELECT p.name FROM role_permissions rp JOIN permissions p ON p.id = rp.permission_id \
+             JOIN user_roles r ON r.id = rp.role_id AND r.deleted_at IS NULL \
+             WHERE rp.role_id = $1 ORDER BY p.name"
+        );
+    }
 }
@@ -1,9 +1,47 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::modules::user_role::persistence::row::UserRoleRow;
+use crate::{
+    modules::user_role::domain::model::{
+        UpdateUserRolePayload, UserRoleModel, UserRolePayload, UserRolePermissionsPayload,
+    },
+    presentation::http::common_query::ListQuery,
+};
+
+// ===== GET =====
+#[derive(Debug, Deserialize)]
+pub struct GetUserRoleQuery {
+    #[serde(flatten)]
+    pub base: ListQuery,
+}
 
 #[derive(Debug, Serialize)]
 pub struct GetUserRoleResponse {
     #[serde(flatten)]
-    pub user_role: UserRoleRow,
+    pub user_role: UserRoleModel,
+}
+
+// ===== CREATE =====
+#[derive(Debug, Deserialize)]
+pub struct CreateUserRoleRequest {
+    #[serde(flatten)]
+    pub user_role: UserRolePayload,
+}
+
+// ===== UPDATE =====
+#[derive(Debug, Deserialize)]
+pub struct UpdateUserRoleRequest {
+    #[serde(flatten)]
+    pub user_role: UpdateUserRolePayload,
+}
+
+// ===== PERMISSIONS =====
+#[derive(Debug, Deserialize)]
+pub struct UpdateUserRolePermissionsRequest {
+    #[serde(flatten)]
+    pub permissions: UserRolePermissionsPayload,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetUserRolePermissionsResponse {
+    pub permissions: Vec<String>,
 }
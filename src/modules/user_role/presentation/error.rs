@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+use crate::application::{error::ApplicationError, error_code::ErrorCode};
+
+#[derive(Debug, Error)]
+pub enum UserRoleError {
+    #[error("User role not found")]
+    NotFound,
+
+    #[error("Unknown permissions: {}", .0.join(", "))]
+    UnknownPermissions(Vec<String>),
+
+    #[error("An unexpected error occurred")]
+    Unexpected(#[from] anyhow::Error),
+}
+
+impl From<UserRoleError> for ApplicationError {
+    fn from(err: UserRoleError) -> Self {
+        match err {
+            UserRoleError::NotFound => ApplicationError::NotFound(
+                ErrorCode::USER_ROLE_NOT_FOUND,
+                "User role not found".into(),
+            ),
+
+            UserRoleError::UnknownPermissions(names) => ApplicationError::InvalidField {
+                code: ErrorCode::USER_ROLE_UNKNOWN_PERMISSION,
+                field: "permissions".into(),
+                message: format!("Unknown permissions: {}", names.join(", ")),
+            },
+
+            UserRoleError::Unexpected(e) => ApplicationError::from(e)
+                .with_conflict_codes(&[("name", ErrorCode::USER_ROLE_NAME_TAKEN)]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_permissions_are_an_invalid_field() {
+        let err = ApplicationError::from(UserRoleError::UnknownPermissions(vec![
+            "users:raed".into(),
+            "users:wirte".into(),
+        ]));
+
+        let ApplicationError::InvalidField {
+            code,
+            field,
+            message,
+        } = err
+        else {
+            panic!("expected InvalidField, got {err:?}");
+        };
+
+        assert_eq!(code, ErrorCode::USER_ROLE_UNKNOWN_PERMISSION);
+        assert_eq!(field, "permissions");
+        assert_eq!(message, "Unknown permissions: users:raed, users:wirte");
+    }
+}
@@ -0,0 +1,57 @@
+use validator::{Validate, ValidationErrors};
+
+use crate::{
+    modules::user_role::{
+        domain::spec::UserRoleFilter,
+        presentation::dto::{CreateUserRoleRequest, GetUserRoleQuery, UpdateUserRoleRequest},
+    },
+    presentation::http::common_query::ListQueryImpl,
+};
+
+impl ListQueryImpl for GetUserRoleQuery {
+    fn start(&self) -> Option<i32> {
+        self.base.start
+    }
+    fn limit(&self) -> Option<i32> {
+        self.base.limit
+    }
+    fn keyword(&self) -> Option<&str> {
+        self.base.keyword.as_deref()
+    }
+
+    fn sort_by(&self) -> Option<&str> {
+        self.base.sort_by.as_deref()
+    }
+
+    fn order(&self) -> Option<&str> {
+        self.base.order.as_deref()
+    }
+}
+
+impl Validate for GetUserRoleQuery {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.base.validate()
+    }
+}
+
+impl Validate for CreateUserRoleRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.user_role.validate()
+    }
+}
+
+impl Validate for UpdateUserRoleRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.user_role.validate()
+    }
+}
+
+pub fn build_user_role_filters(query: &GetUserRoleQuery) -> Vec<UserRoleFilter> {
+    let mut filters = Vec::with_capacity(1);
+
+    if let Some(keyword) = &query.base.keyword {
+        filters.push(UserRoleFilter::NameLike(keyword.to_string()));
+    }
+
+    filters
+}
@@ -0,0 +1,156 @@
+use axum::{extract::State, http::StatusCode};
+use tracing::instrument;
+
+use crate::{
+    modules::user_role::{
+        presentation::{
+            dto::{
+                CreateUserRoleRequest, GetUserRolePermissionsResponse, GetUserRoleQuery,
+                GetUserRoleResponse, UpdateUserRolePermissionsRequest, UpdateUserRoleRequest,
+            },
+            mapper::build_user_role_filters,
+        },
+        user_role_repository::UserRoleRepository,
+        user_role_service::UserRoleService,
+    },
+    presentation::{
+        error::HttpError,
+        http::{
+            common_query::ListQueryImpl,
+            common_response::{ListResponse, SingleResponse},
+            extractor::{AppJson, AppPath, ValidatedJson, ValidatedQuery},
+        },
+        state::AppState,
+    },
+};
+
+pub struct UserRoleController;
+
+impl UserRoleController {
+    #[instrument(skip(state))]
+    pub async fn find_all_user_role_handler(
+        State(state): State<AppState>,
+        ValidatedQuery(params): ValidatedQuery<GetUserRoleQuery>,
+    ) -> Result<ListResponse<GetUserRoleResponse>, HttpError> {
+        let repo = UserRoleRepository::new(state.db.clone());
+        let service = UserRoleService::new(repo, state.permissions.clone());
+
+        let filters = build_user_role_filters(&params);
+
+        let (data, total) = service
+            .find_all_user_role_with_count(
+                &filters,
+                params.sort_by(),
+                params.order(),
+                params.limit(),
+                params.start(),
+            )
+            .await?;
+
+        let response = data
+            .into_iter()
+            .map(|item| GetUserRoleResponse { user_role: item })
+            .collect();
+
+        Ok(ListResponse::ok(response, total))
+    }
+
+    #[instrument(skip(state))]
+    pub async fn find_user_role_handler(
+        State(state): State<AppState>,
+        AppPath(id): AppPath<i32>,
+    ) -> Result<SingleResponse<GetUserRoleResponse>, HttpError> {
+        let repo = UserRoleRepository::new(state.db.clone());
+        let service = UserRoleService::new(repo, state.permissions.clone());
+
+        let found = service.find_user_role_by_id(id).await?;
+
+        let response = GetUserRoleResponse { user_role: found };
+
+        Ok(SingleResponse::ok(response))
+    }
+
+    #[instrument(skip(state))]
+    pub async fn create_user_role_handler(
+        State(state): State<AppState>,
+        ValidatedJson(payload): ValidatedJson<CreateUserRoleRequest>,
+    ) -> Result<SingleResponse<GetUserRoleResponse>, HttpError> {
+        let repo = UserRoleRepository::new(state.db.clone());
+        let service = UserRoleService::new(repo, state.permissions.clone());
+
+        let saved = service.create_user_role(payload.user_role).await?;
+
+        let response = GetUserRoleResponse { user_role: saved };
+
+        Ok(SingleResponse::created(response))
+    }
+
+    #[instrument(skip(state))]
+    pub async fn update_user_role_handler(
+        State(state): State<AppState>,
+        AppPath(id): AppPath<i32>,
+        ValidatedJson(payload): ValidatedJson<UpdateUserRoleRequest>,
+    ) -> Result<SingleResponse<GetUserRoleResponse>, HttpError> {
+        let repo = UserRoleRepository::new(state.db.clone());
+        let service = UserRoleService::new(repo, state.permissions.clone());
+
+        let updated = service.update_user_role(id, payload.user_role).await?;
+
+        let response = GetUserRoleResponse { user_role: updated };
+
+        Ok(SingleResponse::ok(response))
+    }
+
+    #[instrument(skip(state))]
+    pub async fn delete_user_role_handler(
+        State(state): State<AppState>,
+        AppPath(id): AppPath<i32>,
+    ) -> Result<StatusCode, HttpError> {
+        let repo = UserRoleRepository::new(state.db.clone());
+        let service = UserRoleService::new(repo, state.permissions.clone());
+
+        service.delete_user_role(id).await?;
+
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    #[instrument(skip(state))]
+    pub async fn find_user_role_permissions_handler(
+        State(state): State<AppState>,
+        AppPath(id): AppPath<i32>,
+    ) -> Result<SingleResponse<GetUserRolePermissionsResponse>, HttpError> {
+        let repo = UserRoleRepository::new(state.db.clone());
+        let service = UserRoleService::new(repo, state.permissions.clone());
+
+        service.find_user_role_by_id(id).await?;
+        let permissions = service.find_permissions(id).await?;
+
+        let mut permissions: Vec<String> = permissions.iter().cloned().collect();
+        permissions.sort();
+
+        Ok(SingleResponse::ok(GetUserRolePermissionsResponse {
+            permissions,
+        }))
+    }
+
+    #[instrument(skip(state))]
+    pub async fn update_user_role_permissions_handler(
+        State(state): State<AppState>,
+        AppPath(id): AppPath<i32>,
+        AppJson(payload): AppJson<UpdateUserRolePermissionsRequest>,
+    ) -> Result<SingleResponse<GetUserRolePermissionsResponse>, HttpError> {
+        let repo = UserRoleRepository::new(state.db.clone());
+        let service = UserRoleService::new(repo, state.permissions.clone());
+
+        let permissions = service
+            .replace_permissions(id, payload.permissions.permissions)
+            .await?;
+
+        let mut permissions: Vec<String> = permissions.iter().cloned().collect();
+        permissions.sort();
+
+        Ok(SingleResponse::ok(GetUserRolePermissionsResponse {
+            permissions,
+        }))
+    }
+}
@@ -0,0 +1,197 @@
+use sqlx::{Acquire, QueryBuilder};
+use tracing::Instrument;
+
+use crate::{
+    config::db::{DbPool, acquire},
+    infrastructure::sql::{
+        error::DbError,
+        order::{Order, OrderBy},
+        pagination::Pagination,
+        trace::query_span,
+    },
+    modules::user_role::{
+        domain::{
+            model::{UpdateUserRolePayload, UserRoleModel, UserRolePayload},
+            spec::{UserRoleFilter, UserRoleOrder},
+        },
+        persistence::{mutation::UserRoleMutation, query::UserRoleQuery, row::UserRoleRow},
+    },
+};
+
+pub struct UserRoleRepository {
+    db: DbPool,
+}
+
+impl UserRoleRepository {
+    pub fn new(db: DbPool) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_all(
+        &self,
+        filters: &[UserRoleFilter],
+        sort_by: UserRoleOrder,
+        order: Option<&str>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<Vec<UserRoleModel>, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+
+        UserRoleQuery::select(&mut qb);
+        UserRoleQuery::filter(&mut qb, filters);
+
+        // ===== ORDER =====
+        let order = Order::from_str(order, sort_by);
+        let orders = OrderBy(vec![order]);
+        UserRoleQuery::order(&mut qb, &orders);
+
+        // ===== PAGINATION =====
+        let pagination = Pagination::new(limit, offset);
+        pagination.apply(&mut qb);
+
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let rows = qb
+            .build_query_as::<UserRoleRow>()
+            .fetch_all(&mut *conn)
+            .instrument(span)
+            .await?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    pub async fn find_one(
+        &self,
+        filters: &[UserRoleFilter],
+    ) -> Result<Option<UserRoleModel>, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+
+        UserRoleQuery::select(&mut qb);
+        UserRoleQuery::filter(&mut qb, filters);
+
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let row = qb
+            .build_query_as::<UserRoleRow>()
+            .fetch_optional(&mut *conn)
+            .instrument(span)
+            .await?;
+
+        Ok(row.map(Into::into))
+    }
+
+    pub async fn count_all(&self, filters: &[UserRoleFilter]) -> Result<i64, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+
+        UserRoleQuery::count(&mut qb);
+        UserRoleQuery::filter(&mut qb, filters);
+
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let count: i64 = qb
+            .build_query_scalar()
+            .fetch_one(&mut *conn)
+            .instrument(span)
+            .await?;
+
+        Ok(count)
+    }
+
+    pub async fn insert(&self, payload: UserRolePayload) -> Result<UserRoleModel, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserRoleMutation::insert(&mut qb, &payload);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let row = qb
+            .build_query_as::<UserRoleRow>()
+            .fetch_one(&mut *conn)
+            .instrument(span)
+            .await
+            .map_err(DbError::from)?;
+        Ok(row.into())
+    }
+
+    pub async fn update(
+        &self,
+        id: i32,
+        payload: UpdateUserRolePayload,
+    ) -> Result<Option<UserRoleModel>, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserRoleMutation::update(&mut qb, id, &payload);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let row = qb
+            .build_query_as::<UserRoleRow>()
+            .fetch_optional(&mut *conn)
+            .instrument(span)
+            .await
+            .map_err(DbError::from)?;
+        Ok(row.map(Into::into))
+    }
+
+    /// Soft-deletes the role, returning the number of rows affected.
+    pub async fn delete(&self, id: i32) -> Result<u64, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserRoleMutation::delete(&mut qb, id);
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let result = qb.build().execute(&mut *conn).instrument(span).await?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn find_permission_names(&self, role_id: i32) -> Result<Vec<String>, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserRoleQuery::select_permission_names(&mut qb, role_id);
+
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let names: Vec<String> = qb
+            .build_query_scalar()
+            .fetch_all(&mut *conn)
+            .instrument(span)
+            .await?;
+
+        Ok(names)
+    }
+
+    pub async fn find_unknown_permission_names(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<String>, anyhow::Error> {
+        let mut qb = QueryBuilder::new("");
+        UserRoleQuery::select_unknown_permission_names(&mut qb, names);
+
+        let mut conn = acquire(&self.db).await?;
+        let span = query_span(&qb);
+        let unknown: Vec<String> = qb
+            .build_query_scalar()
+            .fetch_all(&mut *conn)
+            .instrument(span)
+            .await?;
+
+        Ok(unknown)
+    }
+
+    pub async fn replace_permissions(
+        &self,
+        role_id: i32,
+        permissions: &[String],
+    ) -> Result<(), anyhow::Error> {
+        let mut conn = acquire(&self.db).await?;
+        let mut tx = conn.begin().await?;
+
+        let mut qb = QueryBuilder::new("");
+        UserRoleMutation::clear_permissions(&mut qb, role_id);
+        let span = query_span(&qb);
+        qb.build().execute(&mut *tx).instrument(span).await?;
+
+        let mut qb = QueryBuilder::new("");
+        UserRoleMutation::assign_permissions(&mut qb, role_id, permissions);
+        let span = query_span(&qb);
+        qb.build().execute(&mut *tx).instrument(span).await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use axum::{Router, handler::Handler, middleware::from_fn_with_state, routing::get};
+
+use crate::{
+    modules::user_role::{
+        domain::policy::UserRolePolicy, user_role_controller::UserRoleController,
+    },
+    presentation::{middleware::auth::require_policy, state::AppState},
+};
+
+pub struct UserRoleRoute;
+
+impl UserRoleRoute {
+    pub fn routes() -> Router<AppState> {
+        Router::new()
+            .route(
+                "/",
+                get(UserRoleController::find_all_user_role_handler
+                    .layer(from_fn_with_state(UserRolePolicy::READ, require_policy)))
+                .post(
+                    UserRoleController::create_user_role_handler
+                        .layer(from_fn_with_state(UserRolePolicy::WRITE, require_policy)),
+                ),
+            )
+            .route(
+                "/{id}",
+                get(UserRoleController::find_user_role_handler
+                    .layer(from_fn_with_state(UserRolePolicy::READ, require_policy)))
+                .put(
+                    UserRoleController::update_user_role_handler
+                        .layer(from_fn_with_state(UserRolePolicy::WRITE, require_policy)),
+                )
+                .delete(
+                    UserRoleController::delete_user_role_handler
+                        .layer(from_fn_with_state(UserRolePolicy::WRITE, require_policy)),
+                ),
+            )
+            .route(
+                "/{id}/permissions",
+                get(UserRoleController::find_user_role_permissions_handler
+                    .layer(from_fn_with_state(UserRolePolicy::READ, require_policy)))
+                .put(
+                    UserRoleController::update_user_role_permissions_handler
+                        .layer(from_fn_with_state(UserRolePolicy::WRITE, require_policy)),
+                ),
+            )
+    }
+}
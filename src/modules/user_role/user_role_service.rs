@@ -0,0 +1,193 @@
+use std::{collections::HashSet, sync::Arc};
+
+use tracing::instrument;
+
+use crate::{
+    application::{authorization::PermissionCache, error::ApplicationError},
+    modules::user_role::{
+        domain::{
+            model::{UpdateUserRolePayload, UserRoleModel, UserRolePayload},
+            spec::{UserRoleFilter, UserRoleOrder},
+        },
+        presentation::error::UserRoleError,
+        user_role_repository::UserRoleRepository,
+    },
+};
+
+pub struct UserRoleService {
+    repo: UserRoleRepository,
+    permissions: PermissionCache,
+}
+
+impl UserRoleService {
+    pub fn new(repo: UserRoleRepository, permissions: PermissionCache) -> Self {
+        Self { repo, permissions }
+    }
+
+    #[instrument(skip(self, filters))]
+    pub async fn find_all_user_role_with_count(
+        &self,
+        filters: &[UserRoleFilter],
+        sort_by: Option<&str>,
+        order: Option<&str>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<(Vec<UserRoleModel>, i64), ApplicationError> {
+        // ===== FILTER =====
+        let mut effective_filters = Vec::with_capacity(1 + filters.len());
+        effective_filters.push(UserRoleFilter::IsDeleted(false));
+        effective_filters.extend_from_slice(filters);
+
+        // ===== ORDER =====
+        let order_field = match sort_by {
+            Some("name") => UserRoleOrder::Name,
+            Some("created_at") => UserRoleOrder::CreatedAt,
+            _ => UserRoleOrder::Id,
+        };
+
+        // ===== EXECUTE PARALLEL QUERY =====
+        let (total_res, data_res) = tokio::join!(
+            self.repo.count_all(&effective_filters),
+            self.repo
+                .find_all(&effective_filters, order_field, order, limit, offset)
+        );
+
+        let total = total_res.map_err(UserRoleError::Unexpected)?;
+        let data = data_res.map_err(UserRoleError::Unexpected)?;
+
+        Ok((data, total))
+    }
+
+    #[instrument(skip(self))]
+    pub async fn find_user_role_by_id(&self, id: i32) -> Result<UserRoleModel, ApplicationError> {
+        let filters = [UserRoleFilter::IsDeleted(false), UserRoleFilter::Id(id)];
+
+        let data = self
+            .repo
+            .find_one(&filters)
+            .await
+            .map_err(UserRoleError::Unexpected)?
+            .ok_or(UserRoleError::NotFound)?;
+
+        Ok(data)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn find_user_role_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<UserRoleModel>, ApplicationError> {
+        let filters = [
+            UserRoleFilter::IsDeleted(false),
+            UserRoleFilter::Name(name.to_string()),
+        ];
+
+        let data = self
+            .repo
+            .find_one(&filters)
+            .await
+            .map_err(UserRoleError::Unexpected)?;
+
+        Ok(data)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn create_user_role(
+        &self,
+        payload: UserRolePayload,
+    ) -> Result<UserRoleModel, ApplicationError> {
+        let data = self
+            .repo
+            .insert(payload)
+            .await
+            .map_err(UserRoleError::Unexpected)?;
+
+        Ok(data)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn update_user_role(
+        &self,
+        id: i32,
+        payload: UpdateUserRolePayload,
+    ) -> Result<UserRoleModel, ApplicationError> {
+        let data = self
+            .repo
+            .update(id, payload)
+            .await
+            .map_err(UserRoleError::Unexpected)?
+            .ok_or(UserRoleError::NotFound)?;
+
+        Ok(data)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn delete_user_role(&self, id: i32) -> Result<(), ApplicationError> {
+        let affected = self
+            .repo
+            .delete(id)
+            .await
+            .map_err(UserRoleError::Unexpected)?;
+
+        if affected == 0 {
+            return Err(UserRoleError::NotFound.into());
+        }
+
+        self.permissions.invalidate(id);
+
+        Ok(())
+    }
+
+    /// Permission names granted to `role_id`, served from the cache when possible.
+    #[instrument(skip(self))]
+    pub async fn find_permissions(
+        &self,
+        role_id: i32,
+    ) -> Result<Arc<HashSet<String>>, ApplicationError> {
+        if let Some(cached) = self.permissions.get(role_id) {
+            return Ok(cached);
+        }
+
+        // Taken before the read so a concurrent invalidation wins.
+        let generation = self.permissions.generation(role_id);
+
+        let names = self
+            .repo
+            .find_permission_names(role_id)
+            .await
+            .map_err(UserRoleError::Unexpected)?;
+
+        Ok(self
+            .permissions
+            .insert(role_id, generation, names.into_iter().collect()))
+    }
+
+    #[instrument(skip(self))]
+    pub async fn replace_permissions(
+        &self,
+        role_id: i32,
+        permissions: Vec<String>,
+    ) -> Result<Arc<HashSet<String>>, ApplicationError> {
+        // Make sure the role exists before touching its assignments.
+        self.find_user_role_by_id(role_id).await?;
+
+        let unknown = self
+            .repo
+            .find_unknown_permission_names(&permissions)
+            .await
+            .map_err(UserRoleError::Unexpected)?;
+
+        if !unknown.is_empty() {
+            return Err(UserRoleError::UnknownPermissions(unknown).into());
+        }
+
+        self.repo
+            .replace_permissions(role_id, &permissions)
+            .await
+            .map_err(UserRoleError::Unexpected)?;
+
+        self.permissions.invalidate(role_id);
+
+        self.find_permissions(role_id).await
+    }
+}
@@ -1,13 +1,19 @@
-use axum::{http::StatusCode, response::IntoResponse, Json};
+use axum::{Json, http::StatusCode, response::IntoResponse};
 use serde::Serialize;
 use validator::ValidationErrors;
 
-use crate::application::error::ApplicationError;
+use crate::{
+    application::{error::ApplicationError, error_code::ErrorCode},
+    presentation::{problem::ProblemDetails, request_id::RequestId},
+};
 
 #[derive(Serialize)]
 pub struct ErrorResponse<T: Serialize = serde_json::Value> {
+    pub code: ErrorCode,
     pub message: String,
     pub details: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<RequestId>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -16,16 +22,42 @@ pub enum HttpError {
     Validation(ValidationErrors),
 
     #[error("Resource not found")]
-    NotFound(String),
+    NotFound(ErrorCode, String),
 
     #[error("Conflict")]
-    Conflict(String),
+    Conflict(ErrorCode, String),
+
+    #[error("Field conflict")]
+    FieldConflict {
+        code: ErrorCode,
+        field: String,
+        message: String,
+    },
+
+    #[error("Invalid field")]
+    InvalidField {
+        code: ErrorCode,
+        field: String,
+        message: String,
+    },
 
     #[error("Unauthorized")]
-    Unauthorized(String),
+    Unauthorized(ErrorCode, String),
 
     #[error("Forbidden")]
-    Forbidden(String),
+    Forbidden(ErrorCode, String),
+
+    #[error("Unprocessable entity")]
+    UnprocessableEntity(ErrorCode, String),
+
+    /// The request could not be extracted, e.g. a malformed JSON body.
+    #[error("Request rejected")]
+    Rejected {
+        code: ErrorCode,
+        status: StatusCode,
+        message: String,
+        details: Option<serde_json::Value>,
+    },
 
     #[error("Internal server error")]
     Internal,
@@ -36,69 +68,231 @@ impl HttpError {
         HttpError::Validation(errs)
     }
 
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            HttpError::Validation(_) => ErrorCode::VALIDATION_FAILED,
+            HttpError::NotFound(code, _)
+            | HttpError::Conflict(code, _)
+            | HttpError::Unauthorized(code, _)
+            | HttpError::Forbidden(code, _)
+            | HttpError::UnprocessableEntity(code, _) => *code,
+            HttpError::FieldConflict { code, .. }
+            | HttpError::InvalidField { code, .. }
+            | HttpError::Rejected { code, .. } => *code,
+            HttpError::Internal => ErrorCode::INTERNAL_ERROR,
+        }
+    }
+
     fn to_response(&self) -> (StatusCode, Json<ErrorResponse>) {
+        let code = self.code();
+        let request_id = RequestId::current();
+
         match self {
             HttpError::Validation(errs) => {
-                let details = serde_json::to_value(errs).unwrap_or(
+                let mut details = serde_json::to_value(errs).unwrap_or(
                     serde_json::json!({"error": "Failed to serialize validation errors"}),
                 );
+                redact_values(&mut details);
                 (
                     StatusCode::UNPROCESSABLE_ENTITY,
                     Json(ErrorResponse {
+                        code,
                         message: "Payload validation failed".to_string(),
                         details: Some(details),
+                        request_id,
                     }),
                 )
             }
-            HttpError::NotFound(msg) => (
+            HttpError::NotFound(_, msg) => (
                 StatusCode::NOT_FOUND,
                 Json(ErrorResponse {
+                    code,
                     message: msg.clone(),
                     details: None,
+                    request_id,
                 }),
             ),
-            HttpError::Conflict(msg) => (
+            HttpError::Conflict(_, msg) => (
                 StatusCode::CONFLICT,
                 Json(ErrorResponse {
+                    code,
                     message: msg.clone(),
                     details: None,
+                    request_id,
                 }),
             ),
-            HttpError::Unauthorized(msg) => (
+            HttpError::FieldConflict { field, message, .. } => (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse {
+                    code,
+                    message: message.clone(),
+                    details: Some(serde_json::json!({ "field": field })),
+                    request_id,
+                }),
+            ),
+            HttpError::InvalidField { field, message, .. } => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ErrorResponse {
+                    code,
+                    message: message.clone(),
+                    details: Some(serde_json::json!({ "field": field })),
+                    request_id,
+                }),
+            ),
+            HttpError::Unauthorized(_, msg) => (
                 StatusCode::UNAUTHORIZED,
                 Json(ErrorResponse {
+                    code,
                     message: msg.clone(),
                     details: None,
+                    request_id,
                 }),
             ),
-            HttpError::Forbidden(msg) => (
+            HttpError::Forbidden(_, msg) => (
                 StatusCode::FORBIDDEN,
                 Json(ErrorResponse {
+                    code,
                     message: msg.clone(),
                     details: None,
+                    request_id,
+                }),
+            ),
+            HttpError::UnprocessableEntity(_, msg) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ErrorResponse {
+                    code,
+                    message: msg.clone(),
+                    details: None,
+                    request_id,
+                }),
+            ),
+            HttpError::Rejected {
+                status,
+                message,
+                details,
+                ..
+            } => (
+                *status,
+                Json(ErrorResponse {
+                    code,
+                    message: message.clone(),
+                    details: details.clone(),
+                    request_id,
                 }),
             ),
             HttpError::Internal => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
+                    code,
                     message: "Internal server error".to_string(),
                     details: None,
+                    request_id,
                 }),
             ),
         }
     }
 }
 
+impl HttpError {
+    /// The same error as an RFC 7807 document. Validation errors become the
+    /// `errors` extension member, other details are merged in as members.
+    fn to_problem(&self, status: StatusCode, body: &ErrorResponse) -> ProblemDetails {
+        const RESERVED: [&str; 7] = [
+            "type",
+            "title",
+            "status",
+            "detail",
+            "instance",
+            "code",
+            "request_id",
+        ];
+
+        let mut problem = ProblemDetails::new(status, body.message.clone());
+        problem
+            .extensions
+            .insert("code".to_string(), body.code.as_str().into());
+
+        if let Some(request_id) = &body.request_id {
+            problem
+                .extensions
+                .insert("request_id".to_string(), request_id.as_str().into());
+        }
+
+        match (self, &body.details) {
+            (HttpError::Validation(_), Some(details)) => {
+                problem
+                    .extensions
+                    .insert("errors".to_string(), details.clone());
+            }
+            (_, Some(serde_json::Value::Object(members))) => {
+                problem.extensions.extend(
+                    members
+                        .iter()
+                        .filter(|(key, _)| !RESERVED.contains(&key.as_str()))
+                        .map(|(key, value)| (key.clone(), value.clone())),
+                );
+            }
+            (_, Some(details)) => {
+                problem
+                    .extensions
+                    .insert("details".to_string(), details.clone());
+            }
+            (_, None) => {}
+        }
+
+        problem
+    }
+}
+
+/// Strips the submitted `value` that `validator` records in every error's
+/// params, so rejected passwords and other secrets are never echoed back.
+fn redact_values(details: &mut serde_json::Value) {
+    match details {
+        serde_json::Value::Object(map) => {
+            if let Some(serde_json::Value::Object(params)) = map.get_mut("params") {
+                params.remove("value");
+            }
+            map.values_mut().for_each(redact_values);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_values),
+        _ => {}
+    }
+}
+
 impl From<ApplicationError> for HttpError {
     fn from(err: ApplicationError) -> Self {
         match err {
-            ApplicationError::NotFound(msg) => HttpError::NotFound(msg),
+            ApplicationError::NotFound(code, msg) => HttpError::NotFound(code, msg),
 
-            ApplicationError::Conflict(msg) => HttpError::Conflict(msg),
+            ApplicationError::Conflict(code, msg) => HttpError::Conflict(code, msg),
 
-            ApplicationError::Unauthorized(msg) => HttpError::Unauthorized(msg),
+            ApplicationError::FieldConflict {
+                code,
+                field,
+                message,
+            } => HttpError::FieldConflict {
+                code,
+                field,
+                message,
+            },
 
-            ApplicationError::Forbidden(msg) => HttpError::Forbidden(msg),
+            ApplicationError::InvalidField {
+                code,
+                field,
+                message,
+            } => HttpError::InvalidField {
+                code,
+                field,
+                message,
+            },
+
+            ApplicationError::Unauthorized(code, msg) => HttpError::Unauthorized(code, msg),
+
+            ApplicationError::Forbidden(code, msg) => HttpError::Forbidden(code, msg),
+
+            ApplicationError::UnprocessableEntity(code, msg) => {
+                HttpError::UnprocessableEntity(code, msg)
+            }
 
             ApplicationError::Unexpected(e) => {
                 tracing::error!("Internal error: {:?}", e);
@@ -111,6 +305,58 @@ impl From<ApplicationError> for HttpError {
 impl IntoResponse for HttpError {
     fn into_response(self) -> axum::response::Response {
         let (status, json) = self.to_response();
-        (status, json).into_response()
+        let problem = self.to_problem(status, &json);
+
+        // Picked up by the `problem_details` middleware when the client or
+        // the configuration asks for problem+json.
+        let mut res = (status, json).into_response();
+        res.extensions_mut().insert(problem);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_values_in_nested_validation_errors() {
+        let mut details = serde_json::json!({
+            "password": [{ "code": "length", "params": { "min": 8, "value": "hunter2" } }],
+            "address": { "city": [{ "code": "length", "params": { "value": "x" } }] },
+        });
+
+        redact_values(&mut details);
+
+        assert_eq!(
+            details,
+            serde_json::json!({
+                "password": [{ "code": "length", "params": { "min": 8 } }],
+                "address": { "city": [{ "code": "length", "params": {} }] },
+            })
+        );
+    }
+
+    #[test]
+    fn problem_merges_details_into_extension_members() {
+        let err = HttpError::FieldConflict {
+            code: ErrorCode::USER_EMAIL_TAKEN,
+            field: "email".to_string(),
+            message: "Email already taken".to_string(),
+        };
+        let (status, json) = err.to_response();
+        let problem = serde_json::to_value(err.to_problem(status, &json)).unwrap();
+
+        assert_eq!(
+            problem,
+            serde_json::json!({
+                "type": "about:blank",
+                "title": "Conflict",
+                "status": 409,
+                "detail": "Email already taken",
+                "code": "USER_EMAIL_TAKEN",
+                "field": "email",
+            })
+        );
     }
 }
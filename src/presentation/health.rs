@@ -0,0 +1,27 @@
+use crate::{
+    application::health::HealthRegistry,
+    config::db::DbPool,
+    data::env::Env,
+    infrastructure::system::sampler::SystemSampler,
+    modules::health::health_check::{
+        DatabaseCheck, DiskSpaceCheck, MigrationsCheck, SystemSamplerCheck,
+    },
+};
+
+const DEFAULT_MIN_FREE_DISK_PERCENT: f64 = 10.0;
+
+pub fn create_health_registry(db: &DbPool, system: &SystemSampler, env: &Env) -> HealthRegistry {
+    let mut registry = HealthRegistry::new();
+
+    registry
+        .register(DatabaseCheck::new(db.clone()))
+        .register(MigrationsCheck::new(db.clone()))
+        .register(DiskSpaceCheck::new(
+            ".".into(),
+            env.health_min_free_disk_percent
+                .unwrap_or(DEFAULT_MIN_FREE_DISK_PERCENT),
+        ))
+        .register(SystemSamplerCheck::new(system.clone()));
+
+    registry
+}
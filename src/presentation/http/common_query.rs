@@ -1,16 +1,20 @@
 use serde::Deserialize;
-use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use serde_with::serde_as;
+use validator::Validate;
 
 #[serde_as]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate)]
 pub struct ListQuery {
     #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 0))]
     pub start: Option<i32>,
 
     #[serde_as(as = "Option<DisplayFromStr>")]
+    #[validate(range(min = 1, max = 100))]
     pub limit: Option<i32>,
 
+    #[validate(length(max = 100))]
     pub keyword: Option<String>,
 
     pub sort_by: Option<String>,
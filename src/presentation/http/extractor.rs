@@ -0,0 +1,132 @@
+use axum::{
+    extract::{FromRequest, FromRequestParts, RawPathParams, Request},
+    http::request::Parts,
+};
+use serde::de::DeserializeOwned;
+use validator::Validate;
+
+use crate::presentation::{
+    error::HttpError,
+    rejection::{from_json_value, path_rejection, query_rejection},
+};
+
+/// `axum::Json` whose rejections are reported as `HttpError`, so malformed
+/// bodies get the same `ErrorResponse` shape as every other failure.
+///
+/// The body is parsed into a `serde_json::Value` first, so a field of the
+/// wrong type is still named in the error when `T` reaches it through
+/// `#[serde(flatten)]`, as the request DTOs do.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AppJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for AppJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = HttpError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::Json(value) = axum::Json::<serde_json::Value>::from_request(req, state).await?;
+
+        Ok(AppJson(from_json_value(&value)?))
+    }
+}
+
+/// `axum::extract::Query` counterpart of [`AppJson`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AppQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for AppQuery<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = HttpError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match axum::extract::Query::<T>::from_request_parts(parts, state).await {
+            Ok(axum::extract::Query(value)) => Ok(AppQuery(value)),
+            Err(rejection) => Err(query_rejection::<T>(
+                rejection,
+                parts.uri.query().unwrap_or_default(),
+            )),
+        }
+    }
+}
+
+/// `axum::extract::Path` counterpart of [`AppJson`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AppPath<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for AppPath<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = HttpError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match axum::extract::Path::<T>::from_request_parts(parts, state).await {
+            Ok(axum::extract::Path(value)) => Ok(AppPath(value)),
+            Err(rejection) => {
+                // A bare `Path<i32>` does not know its parameter's name, but
+                // the route does when it has just the one.
+                let raw = RawPathParams::from_request_parts(parts, state).await.ok();
+                let param =
+                    raw.as_ref()
+                        .and_then(|raw| match raw.iter().collect::<Vec<_>>().as_slice() {
+                            [(key, _)] => Some(*key),
+                            _ => None,
+                        });
+
+                Err(path_rejection(rejection, param))
+            }
+        }
+    }
+}
+
+/// [`AppJson`] that also runs `T::validate`, rejecting with `422` and the
+/// per-field validation errors when the payload does not pass.
+///
+/// Request DTOs that `#[serde(flatten)]` a payload implement `Validate` by
+/// delegating to it rather than deriving it, so the error keys match the
+/// fields instead of nesting under the wrapper field, e.g. `user` or `base`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = HttpError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let AppJson(value) = AppJson::<T>::from_request(req, state).await?;
+
+        value.validate().map_err(HttpError::validation)?;
+
+        Ok(ValidatedJson(value))
+    }
+}
+
+/// [`AppQuery`] counterpart of [`ValidatedJson`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidatedQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = HttpError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let AppQuery(value) = AppQuery::<T>::from_request_parts(parts, state).await?;
+
+        value.validate().map_err(HttpError::validation)?;
+
+        Ok(ValidatedQuery(value))
+    }
+}
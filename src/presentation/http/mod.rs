@@ -1,2 +1,3 @@
 pub mod common_query;
 pub mod common_response;
+pub mod extractor;
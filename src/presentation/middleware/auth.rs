@@ -0,0 +1,203 @@
+use std::{collections::HashSet, sync::Arc};
+
+use axum::{
+    extract::{OptionalFromRequestParts, Request, State},
+    http::request::Parts,
+    middleware::Next,
+    response::Response,
+};
+use tokio::sync::OnceCell;
+
+use crate::{
+    application::{authorization::Subject, error::ApplicationError, error_code::ErrorCode},
+    modules::{
+        auth::presentation::extractor::CurrentUser,
+        user::domain::model::UserModel,
+        user_role::{user_role_repository::UserRoleRepository, user_role_service::UserRoleService},
+    },
+    presentation::{error::HttpError, state::AppState},
+};
+
+/// Who is calling, as far as authorization is concerned.
+#[derive(Debug, Clone, Default)]
+pub struct Caller {
+    pub user: Option<UserModel>,
+    pub permissions: Arc<HashSet<String>>,
+}
+
+/// Attached to every request by [`authenticate`] and read by
+/// [`require_policy`]. The caller is resolved on first use and at most once
+/// per request, so routes nobody guards never touch the database.
+#[derive(Clone)]
+pub struct AuthContext {
+    state: AppState,
+    caller: Arc<OnceCell<Caller>>,
+}
+
+impl AuthContext {
+    /// Credentials that do not resolve to an active user leave the caller
+    /// anonymous, and guarded routes answer `401` on their own. Failing to
+    /// look the caller up, e.g. while the database is down, is an error.
+    pub async fn caller(&self, parts: &mut Parts) -> Result<&Caller, HttpError> {
+        self.caller
+            .get_or_try_init(|| resolve_caller(parts, &self.state))
+            .await
+    }
+}
+
+async fn resolve_caller(parts: &mut Parts, state: &AppState) -> Result<Caller, HttpError> {
+    let user =
+        match <CurrentUser as OptionalFromRequestParts<AppState>>::from_request_parts(parts, state)
+            .await
+        {
+            Ok(user) => user.map(|u| u.0),
+            Err(e @ HttpError::Unauthorized(..)) => {
+                tracing::debug!(error = %e, "Ignoring invalid credentials");
+                None
+            }
+            Err(e) => return Err(e),
+        };
+
+    let permissions = match user.as_ref().and_then(|u| u.role.as_ref()) {
+        Some(role) => {
+            let repo = UserRoleRepository::new(state.db.clone());
+            let service = UserRoleService::new(repo, state.permissions.clone());
+
+            service.find_permissions(role.id).await?
+        }
+        None => Arc::default(),
+    };
+
+    Ok(Caller { user, permissions })
+}
+
+/// Attaches an [`AuthContext`]; nothing is looked up until it is asked for
+/// the caller.
+pub async fn authenticate(State(state): State<AppState>, mut req: Request, next: Next) -> Response {
+    req.extensions_mut().insert(AuthContext {
+        state,
+        caller: Arc::default(),
+    });
+
+    next.run(req).await
+}
+
+/// Route guard: `.route_layer(from_fn_with_state(UserPolicy::CREATE, require_policy))`.
+pub async fn require_policy(
+    State(policy): State<&'static str>,
+    req: Request,
+    next: Next,
+) -> Result<Response, HttpError> {
+    let (mut parts, body) = req.into_parts();
+
+    let ctx = parts
+        .extensions
+        .get::<AuthContext>()
+        .cloned()
+        .ok_or_else(|| {
+            ApplicationError::Unexpected(anyhow::anyhow!(
+                "`authenticate` middleware is not installed"
+            ))
+        })?;
+
+    let caller = ctx.caller(&mut parts).await?;
+
+    let user = caller.user.as_ref().ok_or_else(|| {
+        HttpError::Unauthorized(ErrorCode::AUTH_REQUIRED, "Authentication required".into())
+    })?;
+
+    let subject = Subject {
+        role: user.role.as_ref().map(|r| r.name.as_str()),
+        permissions: &caller.permissions,
+    };
+
+    ctx.state.policies.authorize(policy, &subject)?;
+
+    Ok(next.run(Request::from_parts(parts, body)).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use axum::http::header::AUTHORIZATION;
+    use metrics_exporter_prometheus::PrometheusBuilder;
+    use sqlx::postgres::PgPoolOptions;
+
+    use super::*;
+    use crate::{
+        application::authorization::PermissionCache,
+        infrastructure::{
+            security::{jwt::JwtKeys, password::PasswordHasher},
+            system::sampler::SystemSampler,
+        },
+        presentation::policy::create_policy_registry,
+    };
+
+    /// Nothing listens on port 1, so every database lookup fails.
+    fn state() -> AppState {
+        AppState {
+            started_at: Instant::now(),
+            startup_complete: Arc::default(),
+            db: PgPoolOptions::new()
+                .acquire_timeout(Duration::from_millis(500))
+                .connect_lazy("postgres://app@127.0.0.1:1/app")
+                .unwrap(),
+            password_hasher: PasswordHasher::new(8, 1, 1).unwrap(),
+            jwt: JwtKeys::new(
+                b"secret",
+                chrono::Duration::minutes(5),
+                chrono::Duration::days(1),
+            ),
+            policies: Arc::new(create_policy_registry()),
+            permissions: PermissionCache::new(),
+            metrics: PrometheusBuilder::new().build_recorder().handle(),
+            system: SystemSampler::new(),
+            health: Arc::default(),
+        }
+    }
+
+    fn parts(authorization: Option<&str>) -> Parts {
+        let mut req = Request::builder();
+        if let Some(value) = authorization {
+            req = req.header(AUTHORIZATION, value);
+        }
+
+        req.body(()).unwrap().into_parts().0
+    }
+
+    fn context(state: AppState) -> AuthContext {
+        AuthContext {
+            state,
+            caller: Arc::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn missing_or_invalid_credentials_leave_the_caller_anonymous() {
+        let state = state();
+
+        for authorization in [None, Some("Bearer not-a-token"), Some("Basic abc")] {
+            let ctx = context(state.clone());
+            let caller = ctx.caller(&mut parts(authorization)).await.unwrap();
+
+            assert!(caller.user.is_none());
+            assert!(caller.permissions.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_user_lookup_is_an_internal_error() {
+        let state = state();
+        let token = state.jwt.issue(1, None).unwrap();
+        let ctx = context(state);
+
+        let authorization = format!("Bearer {}", token.token);
+        let err = ctx
+            .caller(&mut parts(Some(&authorization)))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, HttpError::Internal));
+    }
+}
@@ -0,0 +1,99 @@
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{MatchedPath, Request},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+
+use metrics::Gauge;
+
+use crate::config::metrics::{
+    HTTP_REQUEST_DURATION_SECONDS, HTTP_REQUESTS_IN_FLIGHT, HTTP_REQUESTS_TOTAL,
+};
+
+/// Label for requests no route matched, so probing random paths cannot
+/// blow up the number of series.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Counts requests and records their latency per matched route and status.
+pub async fn track_metrics(req: Request, next: Next) -> Response {
+    let started_at = Instant::now();
+    let method = req.method().clone();
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or(UNMATCHED_ROUTE, MatchedPath::as_str)
+        .to_string();
+
+    let in_flight = InFlight::enter();
+    let res = next.run(req).await;
+    drop(in_flight);
+
+    record_request(&method, route, res.status(), started_at.elapsed());
+
+    res
+}
+
+/// Keeps a request in the in-flight gauge until dropped, including when the
+/// client disconnects and the handler future is cancelled.
+struct InFlight(Gauge);
+
+impl InFlight {
+    fn enter() -> Self {
+        let gauge = metrics::gauge!(HTTP_REQUESTS_IN_FLIGHT);
+        gauge.increment(1);
+        Self(gauge)
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.decrement(1);
+    }
+}
+
+fn record_request(method: &Method, route: String, status: StatusCode, elapsed: Duration) {
+    let labels = [
+        ("method", method.to_string()),
+        ("route", route),
+        ("status", status.as_u16().to_string()),
+    ];
+
+    metrics::counter!(HTTP_REQUESTS_TOTAL, &labels).increment(1);
+    metrics::histogram!(HTTP_REQUEST_DURATION_SECONDS, &labels).record(elapsed.as_secs_f64());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::metrics::recorder_builder;
+
+    use super::*;
+
+    #[test]
+    fn records_requests_per_route_and_status() {
+        let recorder = recorder_builder().unwrap().build_recorder();
+        let handle = recorder.handle();
+
+        metrics::with_local_recorder(&recorder, || {
+            for _ in 0..2 {
+                record_request(
+                    &Method::GET,
+                    "/api/users/{id}".to_string(),
+                    StatusCode::OK,
+                    Duration::from_millis(30),
+                );
+            }
+        });
+
+        let output = handle.render();
+
+        assert!(output.contains(
+            r#"http_requests_total{method="GET",route="/api/users/{id}",status="200"} 2"#
+        ));
+        assert!(output.contains(
+            r#"http_request_duration_seconds_bucket{method="GET",route="/api/users/{id}",status="200",le="0.05"} 2"#
+        ));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod auth;
+pub mod metrics;
+pub mod problem;
+pub mod request_id;
+pub mod trace;
@@ -0,0 +1,47 @@
+use axum::{
+    Json,
+    extract::{Request, State},
+    http::{
+        HeaderValue,
+        header::{CONTENT_LENGTH, CONTENT_TYPE},
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::presentation::problem::{
+    ErrorFormat, PROBLEM_JSON, ProblemDetails, accepts_problem_json,
+};
+
+/// Re-renders `HttpError` responses as RFC 7807 documents when `format` is
+/// `Problem` or the request's `Accept` header asks for problem+json. Other
+/// responses pass through untouched.
+pub async fn problem_details(
+    State(format): State<ErrorFormat>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let wanted = format == ErrorFormat::Problem || accepts_problem_json(req.headers());
+    let instance = req.uri().path().to_string();
+
+    let mut res = next.run(req).await;
+
+    if !wanted {
+        return res;
+    }
+
+    let Some(mut problem) = res.extensions_mut().remove::<ProblemDetails>() else {
+        return res;
+    };
+    problem.instance = Some(instance);
+
+    let (mut parts, _) = res.into_parts();
+    parts.headers.remove(CONTENT_LENGTH);
+    parts
+        .headers
+        .insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+
+    let body = Json(problem).into_response().into_body();
+
+    Response::from_parts(parts, body)
+}
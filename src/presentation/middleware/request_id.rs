@@ -0,0 +1,22 @@
+use axum::{extract::Request, middleware::Next, response::Response};
+
+use crate::presentation::request_id::{RequestId, X_REQUEST_ID};
+
+/// Accepts the caller's `X-Request-Id` or generates one, makes it available
+/// to the `TraceLayer` span and error bodies, and echoes it in the response.
+/// Must wrap `TraceLayer`, so it is the outermost layer.
+pub async fn request_id(mut req: Request, next: Next) -> Response {
+    let id = req
+        .headers()
+        .get(X_REQUEST_ID)
+        .and_then(RequestId::from_header)
+        .unwrap_or_else(RequestId::generate);
+
+    req.headers_mut().insert(X_REQUEST_ID, id.to_header_value());
+    req.extensions_mut().insert(id.clone());
+
+    let mut res = id.clone().scope(next.run(req)).await;
+    res.headers_mut().insert(X_REQUEST_ID, id.to_header_value());
+
+    res
+}
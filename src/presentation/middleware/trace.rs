@@ -0,0 +1,89 @@
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Span;
+
+/// Names the request span after the matched route, e.g. `GET /api/users/{id}`,
+/// so spans group per endpoint rather than per URI. Must be layered on the
+/// router, where `MatchedPath` is set; unmatched requests keep the
+/// method-only name from `make_span`.
+pub async fn record_route(req: Request, next: Next) -> Response {
+    if let Some(route) = req.extensions().get::<MatchedPath>() {
+        record(&Span::current(), req.method().as_str(), route.as_str());
+    }
+
+    next.run(req).await
+}
+
+fn record(span: &Span, method: &str, route: &str) {
+    span.record("otel.name", format!("{method} {route}"));
+    span.record("http.route", route);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::{
+        Subscriber,
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+    };
+    use tracing_subscriber::{Layer, layer::Context, prelude::*};
+
+    use super::*;
+
+    /// Collects every field recorded after a span was created.
+    #[derive(Clone, Default)]
+    struct Recorded(Arc<Mutex<Vec<(String, String)>>>);
+
+    impl Visit for Recorded {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((field.name().to_string(), value.to_string()));
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for Recorded {
+        fn on_new_span(&self, _: &Attributes<'_>, _: &Id, _: Context<'_, S>) {}
+
+        fn on_record(&self, _: &Id, values: &Record<'_>, _: Context<'_, S>) {
+            values.record(&mut self.clone());
+        }
+    }
+
+    #[test]
+    fn records_the_route_on_the_request_span() {
+        let recorded = Recorded::default();
+        let subscriber = tracing_subscriber::registry().with(recorded.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!(
+                "request",
+                otel.name = "GET",
+                http.route = tracing::field::Empty,
+            );
+
+            record(&span, "GET", "/api/users/{id}");
+        });
+
+        assert_eq!(
+            *recorded.0.lock().unwrap(),
+            [
+                ("otel.name".to_string(), "GET /api/users/{id}".to_string()),
+                ("http.route".to_string(), "/api/users/{id}".to_string()),
+            ]
+        );
+    }
+}
@@ -1,4 +1,11 @@
 pub mod error;
+pub mod health;
 pub mod http;
+pub mod middleware;
+pub mod policy;
+pub mod problem;
+pub mod rejection;
+pub mod request_id;
 pub mod router;
 pub mod state;
+pub mod trace;
@@ -0,0 +1,17 @@
+use crate::{
+    application::authorization::PolicyRegistry,
+    modules::{
+        auth::domain::policy::AuthPolicy, user::domain::policy::UserPolicy,
+        user_role::domain::policy::UserRolePolicy,
+    },
+};
+
+pub fn create_policy_registry() -> PolicyRegistry {
+    let mut registry = PolicyRegistry::new();
+
+    AuthPolicy::register(&mut registry);
+    UserPolicy::register(&mut registry);
+    UserRolePolicy::register(&mut registry);
+
+    registry
+}
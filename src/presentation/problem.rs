@@ -0,0 +1,106 @@
+use axum::http::{HeaderMap, StatusCode, header::ACCEPT};
+use serde::Serialize;
+
+use crate::data::env::Env;
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// How error responses are rendered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// `{ message, details }`, unless the request asks for problem+json.
+    #[default]
+    Default,
+    /// RFC 7807 `application/problem+json` for every request.
+    Problem,
+}
+
+impl ErrorFormat {
+    pub fn from_env(env: &Env) -> Self {
+        match env.problem_details {
+            Some(true) => ErrorFormat::Problem,
+            _ => ErrorFormat::Default,
+        }
+    }
+}
+
+/// An RFC 7807 problem document. `HttpError` attaches one to every error
+/// response; `instance` is filled in once the request path is known.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+
+    /// Extension members, e.g. `errors` for payload validation failures.
+    #[serde(flatten)]
+    pub extensions: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode, detail: impl Into<String>) -> Self {
+        Self {
+            type_uri: "about:blank".to_string(),
+            title: status.canonical_reason().unwrap_or("Unknown").to_string(),
+            status: status.as_u16(),
+            detail: detail.into(),
+            instance: None,
+            extensions: serde_json::Map::new(),
+        }
+    }
+}
+
+/// Whether `Accept` lists problem+json with a non-zero quality.
+pub fn accepts_problem_json(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|range| {
+            let mut parts = range.split(';').map(str::trim);
+            let media_type = parts.next().unwrap_or_default();
+            let rejected = parts.any(|param| {
+                param
+                    .strip_prefix("q=")
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .is_some_and(|q| q == 0.0)
+            });
+
+            media_type.eq_ignore_ascii_case(PROBLEM_JSON) && !rejected
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn accept(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn detects_problem_json_among_media_ranges() {
+        assert!(accepts_problem_json(&accept("application/problem+json")));
+        assert!(accepts_problem_json(&accept(
+            "application/json;q=0.9, Application/Problem+JSON"
+        )));
+    }
+
+    #[test]
+    fn ignores_other_and_refused_media_types() {
+        assert!(!accepts_problem_json(&HeaderMap::new()));
+        assert!(!accepts_problem_json(&accept("application/json, */*")));
+        assert!(!accepts_problem_json(&accept(
+            "application/problem+json;q=0"
+        )));
+    }
+}
@@ -0,0 +1,423 @@
+use std::error::Error;
+
+use axum::{
+    extract::Query,
+    extract::path::ErrorKind,
+    extract::rejection::{JsonRejection, PathRejection, QueryRejection},
+    http::{StatusCode, Uri},
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::{application::error_code::ErrorCode, presentation::error::HttpError};
+
+impl From<JsonRejection> for HttpError {
+    fn from(rejection: JsonRejection) -> Self {
+        match rejection {
+            JsonRejection::JsonDataError(err) => {
+                let (field, reason) = deserialize_error::<serde_json::Error>(&err);
+                rejected(
+                    ErrorCode::INVALID_JSON_BODY,
+                    err.status(),
+                    "Invalid JSON body",
+                    field,
+                    reason,
+                )
+            }
+            JsonRejection::JsonSyntaxError(err) => {
+                let (_, reason) = deserialize_error::<serde_json::Error>(&err);
+                rejected(
+                    ErrorCode::MALFORMED_JSON_BODY,
+                    err.status(),
+                    "Malformed JSON body",
+                    None,
+                    reason,
+                )
+            }
+            JsonRejection::MissingJsonContentType(err) => rejected(
+                ErrorCode::UNSUPPORTED_CONTENT_TYPE,
+                err.status(),
+                "Expected request with `Content-Type: application/json`",
+                None,
+                None,
+            ),
+            other => rejected(
+                ErrorCode::INVALID_REQUEST,
+                other.status(),
+                &other.body_text(),
+                None,
+                None,
+            ),
+        }
+    }
+}
+
+impl From<QueryRejection> for HttpError {
+    fn from(rejection: QueryRejection) -> Self {
+        match rejection {
+            QueryRejection::FailedToDeserializeQueryString(err) => {
+                let (field, reason) = deserialize_error::<serde::de::value::Error>(&err);
+                rejected(
+                    ErrorCode::INVALID_QUERY,
+                    err.status(),
+                    "Invalid query string",
+                    field,
+                    reason,
+                )
+            }
+            other => rejected(
+                ErrorCode::INVALID_REQUEST,
+                other.status(),
+                &other.body_text(),
+                None,
+                None,
+            ),
+        }
+    }
+}
+
+impl From<PathRejection> for HttpError {
+    fn from(rejection: PathRejection) -> Self {
+        path_rejection(rejection, None)
+    }
+}
+
+/// Converts a `QueryRejection`, retrying `T` against the raw `query` to name
+/// the offending parameter when serde cannot, see [`blame_key`].
+pub fn query_rejection<T>(rejection: QueryRejection, query: &str) -> HttpError
+where
+    T: DeserializeOwned,
+{
+    let QueryRejection::FailedToDeserializeQueryString(err) = rejection else {
+        return rejection.into();
+    };
+
+    let (field, reason) = deserialize_error::<serde::de::value::Error>(&err);
+    let field = field.or_else(|| {
+        let pairs = query.split('&').filter(|pair| !pair.is_empty());
+        let keys = pairs
+            .clone()
+            .map(|pair| pair.split('=').next().unwrap_or(pair));
+
+        blame_key(keys, reason.as_deref()?, |key| {
+            let rest = pairs
+                .clone()
+                .filter(|pair| pair.split('=').next() != Some(key))
+                .collect::<Vec<_>>()
+                .join("&");
+
+            query_error::<T>(&rest)
+        })
+    });
+
+    rejected(
+        ErrorCode::INVALID_QUERY,
+        err.status(),
+        "Invalid query string",
+        field,
+        reason,
+    )
+}
+
+fn query_error<T>(query: &str) -> Option<String>
+where
+    T: DeserializeOwned,
+{
+    let uri = format!("/?{query}").parse::<Uri>().ok()?;
+
+    match Query::<T>::try_from_uri(&uri) {
+        Ok(_) => None,
+        Err(QueryRejection::FailedToDeserializeQueryString(err)) => {
+            deserialize_error::<serde::de::value::Error>(&err).1
+        }
+        Err(other) => Some(other.body_text()),
+    }
+}
+
+/// Deserializes an already parsed JSON body into `T`. Unlike
+/// `JsonRejection::JsonDataError`, the error names the offending field even
+/// when it sits behind `#[serde(flatten)]`, see [`blame_key`].
+pub fn from_json_value<T>(value: &Value) -> Result<T, HttpError>
+where
+    T: DeserializeOwned,
+{
+    let err = match serde_path_to_error::deserialize::<_, T>(value) {
+        Ok(data) => return Ok(data),
+        Err(err) => err,
+    };
+
+    let (field, reason) = path_and_reason(&err);
+    let field = field.or_else(|| {
+        let Value::Object(members) = value else {
+            return None;
+        };
+
+        blame_key(members.keys().map(String::as_str), &reason, |key| {
+            let mut rest = members.clone();
+            rest.remove(key);
+
+            serde_path_to_error::deserialize::<_, T>(&Value::Object(rest))
+                .err()
+                .map(|err| err.inner().to_string())
+        })
+    });
+
+    Err(rejected(
+        ErrorCode::INVALID_JSON_BODY,
+        StatusCode::UNPROCESSABLE_ENTITY,
+        "Invalid JSON body",
+        field,
+        Some(reason),
+    ))
+}
+
+/// Finds the top-level key behind `reason` when serde could not name it, as
+/// with type errors inside `#[serde(flatten)]`, where the nested struct is
+/// deserialized from a buffer and the path is lost. `error_without` reruns
+/// the deserialization with one key removed; the first key whose removal
+/// changes the error is the one to blame.
+fn blame_key<'a>(
+    mut keys: impl Iterator<Item = &'a str>,
+    reason: &str,
+    error_without: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    keys.find(|key| error_without(key).as_deref() != Some(reason))
+        .map(str::to_string)
+}
+
+/// Converts a `PathRejection`, naming `param` as the field when axum cannot
+/// tell which parameter failed, as with a bare `Path<i32>`.
+pub fn path_rejection(rejection: PathRejection, param: Option<&str>) -> HttpError {
+    let PathRejection::FailedToDeserializePathParams(err) = rejection else {
+        tracing::error!("Path extraction misconfigured: {}", rejection.body_text());
+        return HttpError::Internal;
+    };
+
+    let (field, reason) = match err.kind() {
+        ErrorKind::ParseErrorAtKey {
+            key,
+            value,
+            expected_type,
+        } => (Some(key.clone()), expected(value, expected_type)),
+        ErrorKind::ParseErrorAtIndex {
+            index,
+            value,
+            expected_type,
+        } => (Some(index.to_string()), expected(value, expected_type)),
+        ErrorKind::ParseError {
+            value,
+            expected_type,
+        } => (param.map(str::to_string), expected(value, expected_type)),
+        ErrorKind::InvalidUtf8InPathParam { key } => {
+            (Some(key.clone()), "value is not valid UTF-8".to_string())
+        }
+        ErrorKind::DeserializeError { key, message, .. } => (Some(key.clone()), message.clone()),
+        _ if err.status().is_server_error() => {
+            tracing::error!("Path extraction misconfigured: {}", err.body_text());
+            return HttpError::Internal;
+        }
+        _ => (None, err.body_text()),
+    };
+
+    rejected(
+        ErrorCode::INVALID_PATH_PARAMETER,
+        err.status(),
+        "Invalid path parameter",
+        field,
+        Some(reason),
+    )
+}
+
+fn expected(value: &str, expected_type: &str) -> String {
+    format!("expected {expected_type}, got `{value}`")
+}
+
+fn rejected(
+    code: ErrorCode,
+    status: StatusCode,
+    message: &str,
+    field: Option<String>,
+    reason: Option<String>,
+) -> HttpError {
+    let details =
+        (field.is_some() || reason.is_some()).then(|| json!({ "field": field, "reason": reason }));
+
+    HttpError::Rejected {
+        code,
+        status,
+        message: message.to_string(),
+        details,
+    }
+}
+
+/// Digs the `serde_path_to_error` error out of an axum rejection, returning
+/// the path of the offending field and the underlying reason.
+fn deserialize_error<E>(rejection: &dyn Error) -> (Option<String>, Option<String>)
+where
+    E: Error + 'static,
+{
+    let Some(err) = rejection
+        .source()
+        .and_then(Error::source)
+        .and_then(|e| e.downcast_ref::<serde_path_to_error::Error<E>>())
+    else {
+        return (None, None);
+    };
+
+    let (field, reason) = path_and_reason(err);
+
+    (field, Some(reason))
+}
+
+fn path_and_reason<E>(err: &serde_path_to_error::Error<E>) -> (Option<String>, String)
+where
+    E: Error,
+{
+    let reason = err.inner().to_string();
+    let path = err.path().to_string();
+
+    // Missing and unknown fields are reported against the parent, which for
+    // flattened payloads is the root, so fall back to serde's message. Type
+    // errors inside `#[serde(flatten)]` carry neither; see `blame_key`.
+    let field = if path == "." {
+        field_from_message(&reason)
+    } else {
+        Some(path)
+    };
+
+    (field, reason)
+}
+
+fn field_from_message(message: &str) -> Option<String> {
+    ["missing field `", "unknown field `"]
+        .iter()
+        .find_map(|prefix| message.strip_prefix(prefix))
+        .and_then(|rest| rest.split_once('`'))
+        .map(|(field, _)| field.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Json, extract::Query, http::Uri};
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::modules::user::presentation::dto::{
+        CreateUserRequest, GetUserQuery, PurgeDeletedUsersQuery,
+    };
+
+    #[allow(dead_code)]
+    #[derive(Debug, Deserialize)]
+    struct Payload {
+        name: String,
+        age: u8,
+    }
+
+    fn details(err: HttpError) -> (StatusCode, serde_json::Value) {
+        match err {
+            HttpError::Rejected {
+                status, details, ..
+            } => (status, details.unwrap_or_default()),
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn json_type_error_names_the_field() {
+        let rejection = Json::<Payload>::from_bytes(br#"{"name":"a","age":"x"}"#).unwrap_err();
+
+        let (status, details) = details(rejection.into());
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(details["field"], "age");
+    }
+
+    #[test]
+    fn json_missing_field_names_the_field() {
+        let rejection = Json::<Payload>::from_bytes(br#"{"name":"a"}"#).unwrap_err();
+
+        let (_, details) = details(rejection.into());
+
+        assert_eq!(details["field"], "age");
+    }
+
+    #[test]
+    fn json_syntax_error_is_bad_request() {
+        let rejection = Json::<Payload>::from_bytes(b"{").unwrap_err();
+        let err = HttpError::from(rejection);
+
+        assert_eq!(err.code(), ErrorCode::MALFORMED_JSON_BODY);
+
+        let (status, details) = details(err);
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(details["field"].is_null());
+    }
+
+    #[test]
+    fn query_error_names_the_field() {
+        let uri: Uri = "/?name=a&age=old".parse().unwrap();
+        let rejection = Query::<Payload>::try_from_uri(&uri).unwrap_err();
+
+        let (status, details) = details(rejection.into());
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(details["field"], "age");
+    }
+
+    #[test]
+    fn flattened_json_type_error_names_the_field() {
+        let body = json!({
+            "email": "jane@example.com",
+            "name": "Jane",
+            "password": "correct horse",
+            "username": 5,
+        });
+
+        let err = from_json_value::<CreateUserRequest>(&body).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::INVALID_JSON_BODY);
+
+        let (status, details) = details(err);
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(details["field"], "username");
+    }
+
+    #[test]
+    fn flattened_json_missing_field_names_the_field() {
+        let body = json!({ "username": "jane", "password": "correct horse", "name": "Jane" });
+
+        let (_, details) = details(from_json_value::<CreateUserRequest>(&body).unwrap_err());
+
+        assert_eq!(details["field"], "email");
+    }
+
+    #[test]
+    fn flattened_query_error_names_the_parameter() {
+        let uri: Uri = "/api/users?keyword=jane&limit=abc&actived=true"
+            .parse()
+            .unwrap();
+        let rejection = Query::<GetUserQuery>::try_from_uri(&uri).unwrap_err();
+
+        let err = query_rejection::<GetUserQuery>(rejection, uri.query().unwrap());
+        assert_eq!(err.code(), ErrorCode::INVALID_QUERY);
+
+        let (status, details) = details(err);
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(details["field"], "limit");
+    }
+
+    #[test]
+    fn purge_query_rejects_zero_days() {
+        let uri: Uri = "/api/users/deleted?older_than_days=0".parse().unwrap();
+        let rejection = Query::<PurgeDeletedUsersQuery>::try_from_uri(&uri).unwrap_err();
+
+        let (_, details) = details(rejection.into());
+
+        assert_eq!(details["field"], "older_than_days");
+
+        let uri: Uri = "/api/users/deleted?older_than_days=1".parse().unwrap();
+        assert!(Query::<PurgeDeletedUsersQuery>::try_from_uri(&uri).is_ok());
+    }
+}
@@ -0,0 +1,95 @@
+use std::{fmt, sync::Arc};
+
+use axum::http::{HeaderName, HeaderValue};
+use serde::Serialize;
+use uuid::Uuid;
+
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied id that is accepted as is; anything longer, empty
+/// or containing non-printable characters is replaced by a generated one.
+const MAX_LEN: usize = 128;
+
+tokio::task_local! {
+    static CURRENT: RequestId;
+}
+
+/// Correlates a request across logs, the `X-Request-Id` response header and
+/// error bodies. Set by the `request_id` middleware.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct RequestId(Arc<str>);
+
+impl RequestId {
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4().to_string().into())
+    }
+
+    /// The id sent by the client or an upstream proxy, if it is usable.
+    pub fn from_header(value: &HeaderValue) -> Option<Self> {
+        let value = value.to_str().ok()?;
+
+        let usable = !value.is_empty()
+            && value.len() <= MAX_LEN
+            && value.chars().all(|c| c.is_ascii_graphic());
+
+        usable.then(|| Self(value.into()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn to_header_value(&self) -> HeaderValue {
+        HeaderValue::from_str(&self.0).expect("request ids are printable ASCII")
+    }
+
+    /// The id of the request being handled, when called from within the
+    /// `request_id` middleware.
+    pub fn current() -> Option<Self> {
+        CURRENT.try_with(Clone::clone).ok()
+    }
+
+    /// Runs `fut` with `self` as the current request id.
+    pub async fn scope<F: Future>(self, fut: F) -> F::Output {
+        CURRENT.scope(self, fut).await
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_printable_client_ids() {
+        let id = RequestId::from_header(&HeaderValue::from_static("req-42_abc.1")).unwrap();
+
+        assert_eq!(id.as_str(), "req-42_abc.1");
+    }
+
+    #[test]
+    fn rejects_empty_oversized_and_spaced_ids() {
+        let long = "a".repeat(MAX_LEN + 1);
+
+        assert!(RequestId::from_header(&HeaderValue::from_static("")).is_none());
+        assert!(RequestId::from_header(&HeaderValue::from_str(&long).unwrap()).is_none());
+        assert!(RequestId::from_header(&HeaderValue::from_static("a b")).is_none());
+    }
+
+    #[tokio::test]
+    async fn current_is_only_set_inside_the_scope() {
+        let id = RequestId::generate();
+
+        assert_eq!(RequestId::current(), None);
+        assert_eq!(
+            id.clone().scope(async { RequestId::current() }).await,
+            Some(id)
+        );
+    }
+}
@@ -1,15 +1,30 @@
-use axum::Router;
+use axum::{Router, middleware::from_fn_with_state};
 
 use crate::{
-    modules::{health::health_route::HealthRoute, user::user_route::UserRoute},
-    presentation::state::AppState,
+    modules::{
+        auth::auth_route::AuthRoute, health::health_route::HealthRoute,
+        metrics::metrics_route::MetricsRoute, user::user_route::UserRoute,
+        user_role::user_role_route::UserRoleRoute,
+    },
+    presentation::{middleware::auth::authenticate, state::AppState},
 };
 
-pub fn create_router() -> Router<AppState> {
-    Router::new().nest(
-        "/api",
-        Router::new()
-            .nest("/health", HealthRoute::routes())
-            .nest("/users", UserRoute::routes()),
-    )
+/// Only `/api` resolves the caller; probes never touch the user tables.
+pub fn create_router(state: &AppState) -> Router<AppState> {
+    Router::new()
+        .nest(
+            "/api",
+            Router::new()
+                .nest("/auth", AuthRoute::routes())
+                .nest("/health", HealthRoute::routes())
+                .nest("/users", UserRoute::routes())
+                .nest("/user-roles", UserRoleRoute::routes())
+                .layer(from_fn_with_state(state.clone(), authenticate)),
+        )
+        .nest("/health", HealthRoute::probe_routes())
+}
+
+/// `/metrics`, merged into the main router or served on its own port.
+pub fn create_metrics_router() -> Router<AppState> {
+    Router::new().nest("/metrics", MetricsRoute::routes())
 }
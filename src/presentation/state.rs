@@ -1,9 +1,33 @@
-use std::time::Instant;
+use std::{
+    sync::{Arc, atomic::AtomicBool},
+    time::Instant,
+};
 
-use crate::config::db::DbPool;
+use metrics_exporter_prometheus::PrometheusHandle;
+
+use crate::{
+    application::{
+        authorization::{PermissionCache, PolicyRegistry},
+        health::HealthRegistry,
+    },
+    config::db::DbPool,
+    infrastructure::{
+        security::{jwt::JwtKeys, password::PasswordHasher},
+        system::sampler::SystemSampler,
+    },
+};
 
 #[derive(Debug, Clone)]
 pub struct AppState {
     pub started_at: Instant,
+    /// Set once `/health/startup` has seen every required dependency up.
+    pub startup_complete: Arc<AtomicBool>,
     pub db: DbPool,
+    pub password_hasher: PasswordHasher,
+    pub jwt: JwtKeys,
+    pub policies: Arc<PolicyRegistry>,
+    pub permissions: PermissionCache,
+    pub metrics: PrometheusHandle,
+    pub system: SystemSampler,
+    pub health: Arc<HealthRegistry>,
 }
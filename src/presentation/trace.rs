@@ -0,0 +1,72 @@
+use axum::http::Request;
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry_http::HeaderExtractor;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::presentation::request_id::RequestId;
+
+/// `TraceLayer` span for a request. It carries the request id, so every
+/// `#[instrument]` span and event beneath it is attributed to the request,
+/// and continues the caller's trace when a W3C `traceparent` header is sent.
+///
+/// `TraceLayer` runs before routing, so the span is named after the method
+/// only; [`record_route`](crate::presentation::middleware::trace::record_route)
+/// adds the matched route once it is known.
+pub fn make_span<B>(req: &Request<B>) -> Span {
+    let request_id = req
+        .extensions()
+        .get::<RequestId>()
+        .map(RequestId::as_str)
+        .unwrap_or_default();
+
+    let span = tracing::info_span!(
+        "request",
+        otel.name = %req.method(),
+        otel.kind = "server",
+        http.route = tracing::field::Empty,
+        method = %req.method(),
+        uri = %req.uri(),
+        version = ?req.version(),
+        request_id,
+    );
+
+    let parent = TraceContextPropagator::new().extract(&HeaderExtractor(req.headers()));
+    // Only fails when no OpenTelemetry layer is installed, i.e. export is off.
+    let _ = span.set_parent(parent);
+
+    span
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+    use opentelemetry_sdk::trace::SdkTracerProvider;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    #[test]
+    fn continues_the_trace_from_traceparent() {
+        let provider = SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        let req = Request::builder()
+            .uri("/api/users/1")
+            .header(
+                "traceparent",
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            )
+            .body(Body::empty())
+            .unwrap();
+
+        let trace_id = tracing::subscriber::with_default(subscriber, || {
+            make_span(&req).context().span().span_context().trace_id()
+        });
+
+        assert_eq!(trace_id.to_string(), "4bf92f3577b34da6a3ce929d0e0e4736");
+    }
+}